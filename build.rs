@@ -0,0 +1,55 @@
+/// Generates the solution registry for the main binary.
+/// Every solution in `src/days/` is registered by its module path, so `all` and `time` can run them in-process.
+use std::{env, fmt::Write, fs, path::Path};
+
+/// The last day of the event configured via `AOC_YEAR`, like `template::LAST_DAY`: 25 up to 2024, 12 from 2025 on.
+fn last_day() -> u8 {
+    match env::var("AOC_YEAR")
+        .ok()
+        .and_then(|year| year.parse::<u16>().ok())
+    {
+        Some(year) if year >= 2025 => 12,
+        _ => 25,
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/days");
+    println!("cargo:rerun-if-env-changed=AOC_YEAR");
+
+    let last_day = last_day();
+
+    let days_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/days");
+
+    let mut days: Vec<String> = fs::read_dir(&days_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+                .filter_map(|path| {
+                    let module = path.file_stem()?.to_str()?.to_string();
+                    let day: u8 = module.strip_prefix('d')?.parse().ok()?;
+                    (1..=last_day).contains(&day).then_some(module)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort();
+
+    let mut code = String::new();
+
+    writeln!(
+        code,
+        "pub const SOLUTIONS: &[advent_of_code::template::registry::RegistryEntry] = &[{}];",
+        days.iter()
+            .map(|module| format!("advent_of_code::days::{module}::SOLUTION"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+    .unwrap();
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, code).unwrap();
+}
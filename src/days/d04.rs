@@ -0,0 +1,68 @@
+use advent_of_code::Grid;
+
+advent_of_code::solution!(4);
+
+const CLEAR: char = '.';
+const ROLL: char = '@';
+
+pub fn parse_input(input: &str) -> Grid<char> {
+    Grid::parse_padded(input, CLEAR, |c| c)
+}
+
+pub fn count_neighbours(idx: usize, grid: &Grid<char>) -> usize {
+    grid.padded_neighbours_8(idx)
+        .into_iter()
+        .filter(|&n| grid[n] == ROLL)
+        .count()
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let grid = parse_input(input);
+    let number_of_acc = grid
+        .cells()
+        .filter(|&idx| grid[idx] == ROLL && count_neighbours(idx, &grid) < 4)
+        .count();
+    Some(number_of_acc as u64)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let mut grid = parse_input(input);
+    // removing a roll only lowers the counts of its neighbours, so removing all accessible rolls at once
+    // ends up with the same rolls removed as removing them one by one.
+    let mut queue: Vec<usize> = grid
+        .cells()
+        .filter(|&idx| grid[idx] == ROLL && count_neighbours(idx, &grid) < 4)
+        .collect();
+    for &idx in &queue {
+        grid[idx] = CLEAR;
+    }
+    let mut number_of_removed = queue.len() as u64;
+    while let Some(dead_idx) = queue.pop() {
+        // padding is never a roll, so only cells of the input are counted.
+        for idx in grid.padded_neighbours_8(dead_idx) {
+            if grid[idx] == ROLL && count_neighbours(idx, &grid) < 4 {
+                grid[idx] = CLEAR;
+                queue.push(idx);
+                number_of_removed += 1;
+            }
+        }
+    }
+    Some(number_of_removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(43));
+    }
+}
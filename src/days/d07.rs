@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use advent_of_code::Grid;
+
+advent_of_code::solution!(7, parse = parse_input -> Grid<char>);
+
+const CLEAR: char = '.';
+const SPLITTER: char = '^';
+const START: char = 'S';
+
+pub fn parse_input(input: &str) -> Grid<char> {
+    Grid::parse_padded(input, CLEAR, |c| c)
+}
+
+pub fn part_one(grid: &Grid<char>) -> Option<u64> {
+    let (start_col, start_row) = grid.position(grid.find(&START)?);
+    let mut activated_spliters = 0;
+    let mut current_beams = vec![false; grid.width()];
+    current_beams[start_col] = true;
+    // Parse line by line, excluding the first line
+    for row in start_row + 1..grid.height() {
+        let mut next_beams = vec![false; grid.width()];
+        for col in 1..grid.width() - 1 {
+            if current_beams[col] {
+                if grid[(col, row)] == SPLITTER {
+                    next_beams[col - 1] = true;
+                    next_beams[col + 1] = true;
+                    activated_spliters += 1;
+                } else {
+                    next_beams[col] = true;
+                }
+            }
+        }
+        current_beams = next_beams;
+    }
+    Some(activated_spliters)
+}
+
+pub fn generate_timelines(
+    entry_point: usize,
+    grid: &Grid<char>,
+    cache: &mut HashMap<usize, u64>,
+) -> u64 {
+    if let Some(timelines) = cache.get(&entry_point) {
+        return *timelines;
+    }
+    let (starting_col, starting_row) = grid.position(entry_point);
+    // We make the beam advance until the next splitter
+    for row in starting_row + 1..grid.height() {
+        if grid[(starting_col, row)] == SPLITTER {
+            let left_idx = grid.index(starting_col - 1, row + 1);
+            let right_idx = grid.index(starting_col + 1, row + 1);
+            let timelines = generate_timelines(left_idx, grid, cache)
+                + generate_timelines(right_idx, grid, cache);
+            cache.insert(entry_point, timelines);
+            return timelines;
+        }
+    }
+    1
+}
+pub fn generate_timelines_iterative(grid: &Grid<char>) -> Option<u64> {
+    let (width, height) = (grid.width(), grid.height());
+    // A cache for the number of timelines originating from each grid cell
+    // Initialize with 1 for the 'end of the grid' base case, or use Option<u64>
+    let mut cache = vec![0; height * width];
+
+    // Iterate backwards through the grid rows
+    // Loop from the last row (height - 1) up to row 0
+    for row in (0..height).rev() {
+        for col in 0..width {
+            let idx = grid.index(col, row);
+
+            if grid[idx] == SPLITTER {
+                // If it's a splitter, the timelines is the sum of timelines
+                // from the two cells *below and to the left/right*
+                // Safely check bounds and use cached values
+                let left_timelines = if col > 0 && row + 1 < height {
+                    cache[grid.index(col - 1, row + 1)]
+                } else {
+                    1
+                };
+                let right_timelines = if col < width - 1 && row + 1 < height {
+                    cache[grid.index(col + 1, row + 1)]
+                } else {
+                    1
+                };
+
+                cache[idx] = left_timelines + right_timelines;
+            } else {
+                // If it's not a splitter, the beam continues straight down
+                // The timelines are the timelines from the cell directly *below*
+                if row + 1 < height {
+                    cache[idx] = cache[grid.index(col, row + 1)];
+                } else {
+                    // Base case: If it's the bottom row and not a splitter, one timeline
+                    cache[idx] = 1;
+                }
+            }
+        }
+    }
+
+    // The result is the timeline count starting from the initial entry point
+    Some(cache[grid.find(&START)?])
+}
+
+pub fn part_two(grid: &Grid<char>) -> Option<u64> {
+    let mut cache = HashMap::new();
+    Some(generate_timelines(grid.find(&START)?, grid, &mut cache))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
+        assert_eq!(result, Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
+        assert_eq!(result, Some(40));
+    }
+}
@@ -0,0 +1,152 @@
+advent_of_code::solution!(8, parse = parse_input -> Vec<(i64, i64, i64)>);
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use ::rayon::prelude::*;
+use advent_of_code::DisjointSet;
+use advent_of_code::template::{Context, Param};
+
+/// Number of closest pairs that part one connects.
+const CONNECTIONS: Param<usize> = Param {
+    puzzle: 1000,
+    example: 10,
+};
+
+pub fn parse_input(input: &str) -> Vec<(i64, i64, i64)> {
+    input
+        .lines()
+        .map(|s| {
+            let mut split = s.split(',').map(|x| x.parse::<i64>().unwrap());
+            let x = split.next().unwrap();
+            let y = split.next().unwrap();
+            let z = split.next().unwrap();
+            (x, y, z)
+        })
+        .collect()
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Edge {
+    distance: i64,
+    i: usize,
+    j: usize,
+}
+
+impl Ord for Edge {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance.cmp(&other.distance)
+    }
+}
+
+impl PartialOrd for Edge {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub fn part_one(input: &[(i64, i64, i64)], ctx: &Context) -> Option<u64> {
+    let input_len = input.len();
+    let k = ctx.param(&CONNECTIONS);
+    let final_heap: BinaryHeap<Edge> = input
+        .par_iter()
+        .enumerate()
+        .flat_map(|(i, ib_ref)| {
+            let ib = *ib_ref;
+            let input_ref = input;
+            (i + 1..input_len).into_par_iter().map(move |j| {
+                let jb = input_ref[j];
+                let x_sub = ib.0 - jb.0;
+                let y_sub = ib.1 - jb.1;
+                let z_sub = ib.2 - jb.2;
+                let sq_distance = x_sub * x_sub + y_sub * y_sub + z_sub * z_sub;
+                Edge {
+                    distance: sq_distance,
+                    i,
+                    j,
+                }
+            })
+        })
+        .fold(
+            || BinaryHeap::with_capacity(k + 1),
+            |mut heap, edge| {
+                if heap.len() < k {
+                    heap.push(edge);
+                } else if edge.distance < heap.peek().unwrap().distance {
+                    heap.pop();
+                    heap.push(edge);
+                }
+                heap
+            },
+        )
+        .reduce(BinaryHeap::new, |mut h1, h2| {
+            for edge in h2.into_iter() {
+                if h1.len() < k {
+                    h1.push(edge);
+                } else if h1.peek().is_some() && edge.distance < h1.peek().unwrap().distance {
+                    h1.pop();
+                    h1.push(edge)
+                }
+            }
+            h1
+        });
+    let mut circuits = DisjointSet::new(input_len);
+    for edge in final_heap.iter() {
+        circuits.union(edge.i, edge.j);
+    }
+
+    let mut sizes: Vec<usize> = circuits.sizes().collect();
+    sizes.sort_by(|a, b| b.cmp(a));
+    let mut res: u64 = 1;
+    sizes.iter().take(3).for_each(|x| res *= (*x) as u64);
+
+    Some(res)
+}
+
+pub fn part_two(input: &[(i64, i64, i64)]) -> Option<u64> {
+    let input_len = input.len();
+    let mut distances: Vec<Edge> = input
+        .par_iter()
+        .enumerate()
+        .flat_map(|(i, ib_ref)| {
+            let ib = *ib_ref;
+            let input_ref = input;
+            (i + 1..input_len).into_par_iter().map(move |j| {
+                let jb = input_ref[j];
+                let x_sub = ib.0 - jb.0;
+                let y_sub = ib.1 - jb.1;
+                let z_sub = ib.2 - jb.2;
+                let sq_distance = x_sub * x_sub + y_sub * y_sub + z_sub * z_sub;
+                Edge {
+                    distance: sq_distance,
+                    i,
+                    j,
+                }
+            })
+        })
+        .collect();
+    distances.sort_by_key(|e| e.distance);
+
+    let mut circuits = DisjointSet::new(input_len);
+    let mut edge_iter = distances.iter();
+    let mut mult: u64 = 0;
+    while circuits.components() > 1 {
+        let edge = edge_iter.next().expect("We must have an edge");
+        if circuits.union(edge.i, edge.j) && circuits.components() == 1 {
+            mult = (input[edge.i].0 * input[edge.j].0).try_into().unwrap();
+        }
+    }
+    Some(mult)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::examples! {
+        parse = parse_input;
+        example("08.txt") {
+            part_one: Some(40),
+            part_two: Some(25272),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+// The solutions of every day. Each module is run by a thin binary in `src/bin/`, which is generated by `cargo scaffold`.
+pub mod d01;
+pub mod d02;
+pub mod d03;
+pub mod d04;
+pub mod d05;
+pub mod d06;
+pub mod d07;
+pub mod d08;
+pub mod d09;
+pub mod d10;
+pub mod d11;
+pub mod d12;
@@ -0,0 +1,166 @@
+/// A disjoint-set forest (union-find) over the elements `0..len`.
+/// Tracks which elements are connected, e.g. the components of a graph while its edges are added one by one.
+/// Uses path compression and union by size, so `find` and `union` take close to constant time.
+///
+/// ```ignore
+/// use advent_of_code::DisjointSet;
+///
+/// let mut set = DisjointSet::new(4);
+/// set.union(0, 1);
+/// set.union(2, 3);
+/// assert!(set.connected(1, 0));
+/// assert_eq!(set.components(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    /// Create `len` sets, each containing one element.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Number of disjoint sets.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// The root of the set containing `i`.
+    /// Points every element on the way to the root directly to it, without recursing.
+    pub fn find(&mut self, i: usize) -> usize {
+        let mut root = i;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = i;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Merge the sets containing `i` and `j`, attaching the smaller one to the larger one.
+    /// Returns `false` if they already were in the same set.
+    pub fn union(&mut self, i: usize, j: usize) -> bool {
+        let (mut root_i, mut root_j) = (self.find(i), self.find(j));
+
+        if root_i == root_j {
+            return false;
+        }
+
+        if self.sizes[root_i] < self.sizes[root_j] {
+            (root_i, root_j) = (root_j, root_i);
+        }
+
+        self.parents[root_j] = root_i;
+        self.sizes[root_i] += self.sizes[root_j];
+        self.components -= 1;
+        true
+    }
+
+    /// Whether `i` and `j` are in the same set.
+    pub fn connected(&mut self, i: usize, j: usize) -> bool {
+        self.find(i) == self.find(j)
+    }
+
+    /// Number of elements in the set containing `i`.
+    pub fn size(&mut self, i: usize) -> usize {
+        let root = self.find(i);
+        self.sizes[root]
+    }
+
+    /// Number of elements of every set, in order of their roots.
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.parents
+            .iter()
+            .enumerate()
+            .filter(|&(i, parent)| i == *parent)
+            .map(|(i, _)| self.sizes[i])
+    }
+
+    /// The elements of every set, in ascending order. Sets are ordered by their smallest element.
+    pub fn groups(&mut self) -> impl Iterator<Item = Vec<usize>> {
+        let mut group_of_root: Vec<Option<usize>> = vec![None; self.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::with_capacity(self.components);
+
+        for i in 0..self.len() {
+            let root = self.find(i);
+            let group = *group_of_root[root].get_or_insert_with(|| {
+                groups.push(Vec::with_capacity(self.sizes[root]));
+                groups.len() - 1
+            });
+            groups[group].push(i);
+        }
+
+        groups.into_iter()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DisjointSet;
+
+    #[test]
+    fn merges_sets() {
+        let mut set = DisjointSet::new(5);
+        assert_eq!(set.components(), 5);
+        assert!(set.union(0, 1));
+        assert!(set.union(3, 1));
+        assert!(!set.union(0, 3));
+        assert!(set.connected(0, 3));
+        assert!(!set.connected(0, 2));
+        assert_eq!(set.components(), 3);
+        assert_eq!(set.size(3), 3);
+        assert_eq!(set.size(4), 1);
+    }
+
+    #[test]
+    fn lists_sizes_and_groups() {
+        let mut set = DisjointSet::new(6);
+        set.union(4, 1);
+        set.union(5, 0);
+        set.union(1, 2);
+
+        let mut sizes: Vec<usize> = set.sizes().collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 2, 3]);
+        assert_eq!(
+            set.groups().collect::<Vec<_>>(),
+            vec![vec![0, 5], vec![1, 2, 4], vec![3]]
+        );
+    }
+
+    #[test]
+    fn compresses_long_chains() {
+        let len = 1_000_000;
+        let mut set = DisjointSet::new(len);
+        // A chain this deep would overflow the stack of a recursive `find`.
+        set.parents = (0..len).map(|i| i.saturating_sub(1)).collect();
+
+        assert_eq!(set.find(len - 1), 0);
+        assert!(set.parents.iter().all(|&parent| parent == 0));
+    }
+}
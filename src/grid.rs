@@ -0,0 +1,332 @@
+/// A two-dimensional grid of cells, stored row by row.
+/// Cells are addressed by index into the rows, or by `(x, y)` coordinates where `(0, 0)` is the top left.
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A grid parsed from the lines of an input, optionally padded with a border of sentinel cells.
+/// The padding lets solutions look at the neighbours of every cell without checking bounds.
+/// Indices and coordinates include the padding, i.e. the first cell of the input is at `(1, 1)` of a padded grid.
+///
+/// ```ignore
+/// use advent_of_code::Grid;
+///
+/// let grid = Grid::parse_padded("@.\n.@", '.', |c| c);
+/// let idx = grid.index(1, 1);
+/// let rolls = grid.neighbours_8(idx).filter(|&n| grid[n] == '@').count();
+/// assert_eq!(rolls, 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    padding: usize,
+    data: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parse every character of the input to a cell with `cell`.
+    ///
+    /// # Panics
+    /// If the lines of the input are not of equal length.
+    pub fn parse(input: &str, cell: impl FnMut(char) -> T) -> Self {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let height = input.lines().count();
+
+        if let Some((y, line)) = input
+            .lines()
+            .enumerate()
+            .find(|(_, line)| line.chars().count() != width)
+        {
+            panic!(
+                "line {} of the grid is {} characters long, expected {width} like the first line.",
+                y + 1,
+                line.chars().count()
+            );
+        }
+
+        let data = input.lines().flat_map(str::chars).map(cell).collect();
+
+        Self {
+            width,
+            height,
+            padding: 0,
+            data,
+        }
+    }
+
+    /// Width of the grid, including the padding.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height of the grid, including the padding.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Index of the cell at `(x, y)`.
+    pub fn index(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    /// Coordinates `(x, y)` of the cell at `idx`.
+    pub fn position(&self, idx: usize) -> (usize, usize) {
+        // a grid without columns has no cells, but must not divide by zero.
+        let width = self.width.max(1);
+        (idx % width, idx / width)
+    }
+
+    /// The cell at `(x, y)`, or `None` if it is outside of the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.data[self.index(x, y)])
+    }
+
+    /// Whether the cell at `idx` is part of the padding.
+    pub fn is_padding(&self, idx: usize) -> bool {
+        let (x, y) = self.position(idx);
+        x < self.padding
+            || y < self.padding
+            || x >= self.width - self.padding
+            || y >= self.height - self.padding
+    }
+
+    /// Indices of all cells that are not padding, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = usize> + '_ {
+        let (p, w, h) = (self.padding, self.width, self.height);
+        (p..h - p).flat_map(move |y| (p..w - p).map(move |x| y * w + x))
+    }
+
+    /// Indices of the neighbours of a cell above, left, right and below it, skipping those outside of the grid.
+    pub fn neighbours_4(&self, idx: usize) -> impl Iterator<Item = usize> + '_ {
+        self.offsets(idx, &NEIGHBOURS_4)
+    }
+
+    /// Indices of the neighbours of a cell including its diagonals, skipping those outside of the grid.
+    pub fn neighbours_8(&self, idx: usize) -> impl Iterator<Item = usize> + '_ {
+        self.offsets(idx, &NEIGHBOURS_8)
+    }
+
+    /// Indices of the neighbours of a cell including its diagonals, without checking bounds.
+    /// Only valid for cells that are not padding of a padded grid, whose neighbours are all inside of it.
+    pub fn padded_neighbours_8(&self, idx: usize) -> [usize; 8] {
+        debug_assert!(self.padding > 0 && !self.is_padding(idx));
+        let w = self.width;
+        [
+            idx - w - 1,
+            idx - w,
+            idx - w + 1,
+            idx - 1,
+            idx + 1,
+            idx + w - 1,
+            idx + w,
+            idx + w + 1,
+        ]
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        idx: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = usize> + 'a {
+        let (x, y) = self.position(idx);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+            (x < self.width && y < self.height).then(|| self.index(x, y))
+        })
+    }
+
+    /// The cells of row `y`, including the padding.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// All rows of the grid, including the padding.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x` from top to bottom, including the padding.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.data.iter().skip(x).step_by(self.width.max(1))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Parse the input like [`Grid::parse`] and surround it with a border of `sentinel` cells.
+    pub fn parse_padded(input: &str, sentinel: T, mut cell: impl FnMut(char) -> T) -> Self {
+        let inner = Self::parse(input, &mut cell);
+        let width = inner.width + 2;
+        let height = inner.height + 2;
+
+        let mut data = vec![sentinel; width * height];
+
+        for (y, row) in inner.rows().enumerate() {
+            let start = (y + 1) * width + 1;
+            data[start..start + row.len()].clone_from_slice(row);
+        }
+
+        Self {
+            width,
+            height,
+            padding: 1,
+            data,
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Index of the first cell equal to `value`, padding excluded.
+    pub fn find(&self, value: &T) -> Option<usize> {
+        self.cells().find(|&idx| self.data[idx] == *value)
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        &self.data[idx]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        &mut self.data[idx]
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        &self.data[self.index(x, y)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let idx = self.index(x, y);
+        &mut self.data[idx]
+    }
+}
+
+/// Prints the grid without its padding, one row per line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (p, w) = (self.padding, self.width);
+
+        for row in self.rows().skip(p).take(self.height - 2 * p) {
+            for cell in &row[p..w - p] {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+
+    const INPUT: &str = "ab\ncd\nef";
+
+    #[test]
+    fn converts_positions() {
+        let grid = Grid::parse(INPUT, |c| c);
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid.index(1, 2), 5);
+        assert_eq!(grid.position(5), (1, 2));
+        assert_eq!(grid[(0, 1)], 'c');
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.find(&'d'), Some(3));
+    }
+
+    #[test]
+    fn pads_with_sentinel() {
+        let grid = Grid::parse_padded(INPUT, '#', |c| c);
+        assert_eq!((grid.width(), grid.height()), (4, 5));
+        assert_eq!(grid.row(0), &['#'; 4]);
+        assert_eq!(grid.row(1), &['#', 'a', 'b', '#']);
+        assert!(grid.is_padding(grid.index(3, 1)));
+        assert!(!grid.is_padding(grid.index(1, 1)));
+        assert_eq!(
+            grid.cells().map(|idx| grid[idx]).collect::<String>(),
+            "abcdef"
+        );
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid = Grid::parse(INPUT, |c| c);
+        let corner: String = grid.neighbours_8(0).map(|idx| grid[idx]).collect();
+        assert_eq!(corner, "bcd");
+        let center: String = grid.neighbours_4(3).map(|idx| grid[idx]).collect();
+        assert_eq!(center, "bcf");
+
+        let padded = Grid::parse_padded(INPUT, '#', |c| c);
+        let a = padded.index(1, 1);
+        assert_eq!(padded.neighbours_8(a).count(), 8);
+        assert!(
+            padded
+                .padded_neighbours_8(a)
+                .into_iter()
+                .eq(padded.neighbours_8(a))
+        );
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = Grid::parse(INPUT, |c| c == 'a' || c == 'd');
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(
+            grid.column(1).copied().collect::<Vec<_>>(),
+            vec![false, true, false]
+        );
+    }
+
+    #[test]
+    fn displays_without_padding() {
+        let grid = Grid::parse_padded(INPUT, '#', |c| c);
+        assert_eq!(grid.to_string(), "ab\ncd\nef\n");
+        assert_eq!(Grid::parse("", |c| c).to_string(), "");
+    }
+
+    #[test]
+    fn handles_empty_grids() {
+        let grid = Grid::parse("", |c| c);
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.cells().count(), 0);
+        assert_eq!(grid.neighbours_8(0).count(), 0);
+        assert!(grid.is_padding(0));
+        assert_eq!(grid.find(&'a'), None);
+
+        let padded = Grid::parse_padded("", '#', |c| c);
+        assert_eq!((padded.width(), padded.height()), (2, 2));
+        assert_eq!(padded.cells().count(), 0);
+        assert!(padded.is_padding(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "line 2 of the grid is 3 characters long, expected 2")]
+    fn rejects_ragged_lines() {
+        Grid::parse("ab\ncde\nf", |c| c);
+    }
+}
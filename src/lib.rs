@@ -1,3 +1,12 @@
+// Lets solutions in `days` refer to this crate as `advent_of_code`, same as the binaries do.
+extern crate self as advent_of_code;
+
+pub mod days;
+mod disjoint_set;
+mod grid;
 pub mod template;
 
+pub use disjoint_set::DisjointSet;
+pub use grid::Grid;
+
 // Use this file to add helper functions and additional modules.
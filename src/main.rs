@@ -1,4 +1,6 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, examples, read, scaffold, solve, stars, time,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
@@ -6,8 +8,26 @@ use advent_of_code::template::Day;
 #[cfg(feature = "today")]
 use std::process;
 
+/// Counts allocations of solutions that run in-process, see `advent_of_code::template::memory`.
+#[cfg(not(feature = "dhat-heap"))]
+#[global_allocator]
+static ALLOCATOR: advent_of_code::template::memory::PeakAlloc =
+    advent_of_code::template::memory::PeakAlloc;
+
+/// Every solution in `src/days/`, registered in this binary by `build.rs`.
+/// Excluded from tests, which already run per solution, and from DHAT builds, which profile each solution binary.
+#[cfg(not(any(test, feature = "dhat-heap")))]
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
+#[cfg(any(test, feature = "dhat-heap"))]
+mod solutions {
+    pub const SOLUTIONS: &[advent_of_code::template::registry::RegistryEntry] = &[];
+}
+
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{Day, commands::time, timeouts::Timeouts};
     use std::process;
 
     pub enum AppArguments {
@@ -17,25 +37,33 @@ mod args {
         Read {
             day: Day,
         },
+        Examples {
+            day: Day,
+            overwrite: bool,
+        },
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
+            examples: u8,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            example: bool,
+            input: Option<String>,
+            threads: Option<usize>,
         },
         All {
             release: bool,
+            timeouts: Timeouts,
+            threads: Option<usize>,
         },
-        Time {
-            all: bool,
-            day: Option<Day>,
-            store: bool,
-        },
+        Time(time::Options),
+        Stars,
         #[cfg(feature = "today")]
         Today,
     }
@@ -46,33 +74,81 @@ mod args {
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                timeouts: parse_timeouts(&mut args)?,
+                threads: args.opt_value_from_str("--threads")?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let chart = args.contains("--chart");
+                let fail_on_regression = args.contains("--fail-on-regression");
+                let regression_threshold = args
+                    .opt_value_from_str("--threshold")?
+                    .unwrap_or(time::DEFAULT_REGRESSION_THRESHOLD);
+                let timeouts = parse_timeouts(&mut args)?;
+                let memory = args.contains("--memory");
+                let threads = args.opt_value_from_str("--threads")?;
+                let scaling = args.contains("--scaling");
+                let export = args.opt_value_from_str("--export")?;
+                let output: Option<String> = args.opt_value_from_str("--output")?;
+
+                let export = match (export, output) {
+                    (Some(format), Some(path)) => Some((format, path)),
+                    (None, None) => None,
+                    _ => return Err("`--export` and `--output` must be used together.".into()),
+                };
+
+                if chart && !store {
+                    return Err("`--chart` can only be used together with `--store`.".into());
+                }
+
+                if scaling && (store || export.is_some() || threads.is_some()) {
+                    return Err(
+                        "`--scaling` can not be combined with `--store`, `--export` or `--threads`."
+                            .into(),
+                    );
+                }
 
-                AppArguments::Time {
-                    all,
+                AppArguments::Time(time::Options {
                     day: args.opt_free_from_str()?,
+                    run_all: all,
                     store,
-                }
+                    chart,
+                    regression_threshold,
+                    fail_on_regression,
+                    timeouts,
+                    track_memory: memory,
+                    export,
+                    threads,
+                    scaling,
+                })
             }
+            Some("stars") => AppArguments::Stars,
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
+            Some("examples") => AppArguments::Examples {
+                day: args.free_from_str()?,
+                overwrite: args.contains("--overwrite"),
+            },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args.opt_value_from_str("--template")?,
+                examples: args.opt_value_from_str("--examples")?.unwrap_or(1),
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                example: args.contains("--example"),
+                input: args.opt_value_from_str("--input")?,
+                threads: args.opt_value_from_str("--threads")?,
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -93,6 +169,14 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Parse the `--timeout [<day>[:<part>]=]<seconds>` options, see [`Timeouts::parse`].
+    fn parse_timeouts(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Timeouts, Box<dyn std::error::Error>> {
+        let specs: Vec<String> = args.values_from_str("--timeout")?;
+        Ok(Timeouts::parse(&specs)?)
+    }
 }
 
 fn main() {
@@ -102,38 +186,70 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::All {
+                release,
+                timeouts,
+                threads,
+            } => {
+                all::handle(release, &timeouts, threads, solutions::SOLUTIONS);
+            }
+            AppArguments::Time(options) => time::handle(&options, solutions::SOLUTIONS),
+            AppArguments::Stars => stars::handle(),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
+            AppArguments::Examples { day, overwrite } => examples::handle(day, overwrite),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                template,
+                examples: example_count,
             } => {
-                scaffold::handle(day, overwrite);
+                // downloading first lets the template use the puzzle's title and example answers.
                 if download {
                     download::handle(day);
                 }
+                scaffold::handle(
+                    day,
+                    overwrite,
+                    template.as_deref().unwrap_or(scaffold::DEFAULT_TEMPLATE),
+                    example_count,
+                );
+                if download {
+                    examples::handle(day, false);
+                }
             }
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                example,
+                input,
+                threads,
+            } => solve::handle(
+                day,
+                release,
+                dhat,
+                submit,
+                example,
+                input.as_deref(),
+                threads,
+            ),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
                         download::handle(day);
+                        scaffold::handle(day, false, scaffold::DEFAULT_TEMPLATE, 1);
+                        examples::handle(day, false);
                         read::handle(day)
                     }
                     None => {
                         eprintln!(
                             "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            the {}th of december. Please use `scaffold` with a specific day.",
+                            advent_of_code::template::LAST_DAY
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,294 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    path::Path,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// Represents the confirmed answers for a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Represents the confirmed answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+/// Result of comparing a part's output with its confirmed answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verification {
+    /// No answer has been confirmed for this part yet.
+    Unknown,
+    Match,
+    Mismatch {
+        expected: String,
+    },
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    /// Fails if the file can not be read or is malformed, so that it is not verified against or overwritten by mistake.
+    pub fn read_from_file() -> Result<Self, String> {
+        Self::read_from(Path::new(ANSWERS_FILE_PATH))
+    }
+
+    fn read_from(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                Answers::try_from(contents).map_err(|e| format!("{}: {e}", path.display()))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    /// Get the confirmed answer for a part, if any.
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.day == day)?;
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Record the confirmed answer for a part, overwriting a previous one.
+    pub fn insert(&mut self, day: Day, part: u8, value: &str) {
+        let index = if let Some(index) = self.data.iter().position(|a| a.day == day) {
+            index
+        } else {
+            self.data.push(Answer {
+                day,
+                part_1: None,
+                part_2: None,
+            });
+            self.data.sort_unstable_by_key(|a| a.day);
+            self.data.iter().position(|a| a.day == day).unwrap()
+        };
+
+        let answer = &mut self.data[index];
+        match part {
+            1 => answer.part_1 = Some(value.into()),
+            2 => answer.part_2 = Some(value.into()),
+            _ => {}
+        }
+    }
+
+    /// Compare the output of a part with its confirmed answer.
+    pub fn verify(&self, day: Day, part: u8, value: &str) -> Verification {
+        match self.get(day, part) {
+            None => Verification::Unknown,
+            Some(expected) if expected == value => Verification::Match,
+            Some(expected) => Verification::Mismatch {
+                expected: expected.into(),
+            },
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        map.insert(
+            "part_1".into(),
+            value
+                .part_1
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "part_2".into(),
+            value
+                .part_2
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        Ok(Answer {
+            day,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answer, Answers, Verification};
+    use crate::day;
+    use std::{env, fs, process};
+
+    fn get_mock_answers() -> Answers {
+        Answers {
+            data: vec![
+                Answer {
+                    day: day!(1),
+                    part_1: Some("3".into()),
+                    part_2: Some("6".into()),
+                },
+                Answer {
+                    day: day!(4),
+                    part_1: Some("13".into()),
+                    part_2: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn reads_answer_files() {
+        let missing = env::temp_dir().join(format!("aoc-answers-missing-{}.json", process::id()));
+        assert!(Answers::read_from(&missing).unwrap().data.is_empty());
+
+        let corrupt = env::temp_dir().join(format!("aoc-answers-corrupt-{}.json", process::id()));
+        fs::write(&corrupt, r#"{ "data": [{ "day": "01", "#).unwrap();
+        let result = Answers::read_from(&corrupt);
+        fs::remove_file(&corrupt).unwrap();
+        assert!(result.unwrap_err().contains("not valid JSON"));
+    }
+
+    #[test]
+    fn handles_json_answers() {
+        let json = r#"{ "data": [{ "day": "01", "part_1": "1234", "part_2": null }] }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(day!(1), 1), Some("1234"));
+        assert_eq!(answers.get(day!(1), 2), None);
+        assert_eq!(answers.get(day!(2), 1), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "data": [{ "day": "26", "part_1": 12 }] }"#.to_string();
+        Answers::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let answers = get_mock_answers();
+        let json = tinyjson::JsonValue::from(answers.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Answers::try_from(json).unwrap();
+        assert_eq!(parsed.data, answers.data);
+    }
+
+    #[test]
+    fn inserts_answers() {
+        let mut answers = get_mock_answers();
+        answers.insert(day!(4), 2, "43");
+        answers.insert(day!(2), 1, "1227775554");
+        answers.insert(day!(1), 1, "4");
+        assert_eq!(answers.data.len(), 3);
+        assert_eq!(answers.data[1].day, day!(2));
+        assert_eq!(answers.get(day!(4), 2), Some("43"));
+        assert_eq!(answers.get(day!(2), 1), Some("1227775554"));
+        assert_eq!(answers.get(day!(1), 1), Some("4"));
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.verify(day!(1), 1, "3"), Verification::Match);
+        assert_eq!(
+            answers.verify(day!(1), 2, "7"),
+            Verification::Mismatch {
+                expected: "6".into()
+            }
+        );
+        assert_eq!(answers.verify(day!(4), 2, "43"), Verification::Unknown);
+    }
+}
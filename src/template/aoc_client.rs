@@ -0,0 +1,397 @@
+/// Built-in HTTP client for the Advent of Code website.
+/// An alternative to the "aoc-cli" command-line wrapper in `aoc_cli`, enabled with the `aoc-client` feature.
+use std::{env, fmt::Display, fs, io, path::PathBuf, time::Duration};
+
+use ureq::Agent;
+
+use crate::template::{Day, YEAR};
+
+const BASE_URL: &str = "https://adventofcode.com";
+/// Sent when `AOC_USER_AGENT` is not set. Advent of Code asks automated tools to identify their user, see the readme.
+const DEFAULT_USER_AGENT: &str = concat!("advent_of_code/", env!("CARGO_PKG_VERSION"));
+const USER_AGENT_ENV: &str = "AOC_USER_AGENT";
+const SESSION_ENV: &str = "ADVENT_OF_CODE_SESSION";
+const SESSION_FILE: &str = ".adventofcode.session";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    /// No session cookie in `ADVENT_OF_CODE_SESSION` or `~/.adventofcode.session`.
+    MissingSession,
+    /// No year configured via `AOC_YEAR`.
+    MissingYear,
+    /// The session cookie was rejected, it probably expired.
+    Unauthorized,
+    /// The puzzle does not exist or is not unlocked yet.
+    NotFound,
+    /// The server answered with an unexpected status code.
+    BadStatus(u16),
+    /// The request could not be sent or the response could not be read.
+    Transport(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set `{SESSION_ENV}` or create `~/{SESSION_FILE}`."
+            ),
+            AocClientError::MissingYear => write!(f, "no year configured, set `AOC_YEAR`."),
+            AocClientError::Unauthorized => {
+                write!(f, "the session cookie was rejected, it might have expired.")
+            }
+            AocClientError::NotFound => write!(f, "the puzzle is not available (yet)."),
+            AocClientError::BadStatus(status) => {
+                write!(f, "the server responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        AocClientError::Transport(e.to_string())
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+pub struct AocClient {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    year: u16,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str, year: u16) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(user_agent())
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').into(),
+            session: session.trim().into(),
+            year,
+        }
+    }
+
+    /// Create a client for adventofcode.com, using the same session cookie locations as aoc-cli.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let year = YEAR.ok_or(AocClientError::MissingYear)?;
+        let session = read_session().ok_or(AocClientError::MissingSession)?;
+        Ok(Self::new(BASE_URL, &session, year))
+    }
+
+    /// Download the puzzle input for a day.
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/day/{}/input", self.year, day.into_inner()))
+    }
+
+    /// Download the puzzle description for a day, converted to markdown.
+    pub fn puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&format!("{}/day/{}", self.year, day.into_inner()))?;
+        Ok(html_to_markdown(&extract_articles(&html)))
+    }
+
+    /// Submit an answer and return the website's response as plain text.
+    pub fn submit(&self, day: Day, part: u8, answer: &str) -> Result<String, AocClientError> {
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.base_url,
+            self.year,
+            day.into_inner()
+        );
+
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])?;
+
+        check_status(response.status().as_u16())?;
+
+        let html = response.body_mut().read_to_string()?;
+        Ok(html_to_markdown(&extract_articles(&html)))
+    }
+
+    /// Download input and puzzle description for a day to `data/inputs` and `data/puzzles`.
+    pub fn download(&self, day: Day) -> Result<(), AocClientError> {
+        let input_path = format!("data/inputs/{day}.txt");
+        let puzzle_path = format!("data/puzzles/{day}.md");
+
+        fs::write(&input_path, self.input(day)?)?;
+        fs::write(&puzzle_path, self.puzzle(day)?)?;
+
+        println!("---");
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+        Ok(())
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        let url = format!("{}/{path}", self.base_url);
+
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call()?;
+
+        check_status(response.status().as_u16())?;
+
+        Ok(response.body_mut().read_to_string()?)
+    }
+}
+
+fn check_status(status: u16) -> Result<(), AocClientError> {
+    match status {
+        200..=299 => Ok(()),
+        // NOTE: the website answers with 400 if the session cookie is invalid.
+        400 | 401 | 403 => Err(AocClientError::Unauthorized),
+        404 => Err(AocClientError::NotFound),
+        status => Err(AocClientError::BadStatus(status)),
+    }
+}
+
+fn user_agent() -> String {
+    env::var(USER_AGENT_ENV)
+        .ok()
+        .filter(|agent| !agent.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_USER_AGENT.into())
+}
+
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var(SESSION_ENV)
+        && !session.trim().is_empty()
+    {
+        return Some(session);
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let session = fs::read_to_string(PathBuf::from(home).join(SESSION_FILE)).ok()?;
+    let session = session.trim();
+
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Extract the contents of all `<article>` elements, which hold the puzzle description and submission responses.
+/// Falls back to the whole document if there are none.
+fn extract_articles(html: &str) -> String {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(content_start) = rest[start..].find('>').map(|i| start + i + 1) else {
+            break;
+        };
+        let Some(end) = rest[content_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[content_start..content_start + end]);
+        rest = &rest[content_start + end..];
+    }
+
+    if articles.is_empty() {
+        html.to_string()
+    } else {
+        articles.join("\n")
+    }
+}
+
+/// Convert the subset of HTML used on the Advent of Code website to markdown.
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+    let mut in_pre = false;
+
+    while let Some(start) = rest.find('<') {
+        out.push_str(&decode_entities(&rest[..start]));
+
+        let Some(end) = rest[start..].find('>').map(|i| start + i) else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let tag = &rest[start + 1..end];
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .find(|s| !s.is_empty())
+            .unwrap_or_default();
+        let is_closing = tag.starts_with('/');
+
+        match (name, is_closing) {
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p", true) => out.push_str("\n\n"),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("ul", true) => out.push('\n'),
+            _ => {}
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    out.push_str(&decode_entities(rest));
+    out.trim().to_string() + "\n"
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(feature = "test_lib", feature = "aoc-client"))]
+mod tests {
+    use super::{AocClient, AocClientError, html_to_markdown};
+    use crate::day;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    /// Serve a single canned response on a local port, returning the base url and the received request.
+    fn stub_server(status: u16, body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut body_buf = vec![0; content_length];
+            reader.read_exact(&mut body_buf).unwrap();
+            request.push_str(&String::from_utf8_lossy(&body_buf));
+
+            let response = format!(
+                "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            tx.send(request).unwrap();
+        });
+
+        (base_url, rx)
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (base_url, requests) = stub_server(200, "1,2,3\n");
+        let client = AocClient::new(&base_url, "secret", 2025);
+
+        assert_eq!(client.input(day!(8)).unwrap(), "1,2,3\n");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2025/day/8/input "));
+        assert!(request.contains("session=secret"));
+    }
+
+    #[test]
+    fn downloads_puzzle() {
+        let (base_url, _) = stub_server(
+            200,
+            "<html><main><article class=\"day-desc\"><h2>--- Day 1: Test ---</h2><p>Answer is <code><em>42</em></code>.</p></article></main></html>",
+        );
+        let client = AocClient::new(&base_url, "secret", 2025);
+
+        assert_eq!(
+            client.puzzle(day!(1)).unwrap(),
+            "## --- Day 1: Test ---\n\nAnswer is `*42*`.\n"
+        );
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, requests) =
+            stub_server(200, "<article><p>That's the right answer!</p></article>");
+        let client = AocClient::new(&base_url, "secret", 2025);
+
+        let response = client.submit(day!(3), 2, "1234").unwrap();
+        assert_eq!(response, "That's the right answer!\n");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2025/day/3/answer "));
+        assert!(request.ends_with("level=2&answer=1234"));
+    }
+
+    #[test]
+    fn maps_error_statuses() {
+        let (base_url, _) = stub_server(400, "Puzzle inputs differ by user.");
+        let client = AocClient::new(&base_url, "expired", 2025);
+        assert!(matches!(
+            client.input(day!(1)),
+            Err(AocClientError::Unauthorized)
+        ));
+
+        let (base_url, _) = stub_server(404, "Not Found");
+        let client = AocClient::new(&base_url, "secret", 2025);
+        assert!(matches!(
+            client.input(day!(12)),
+            Err(AocClientError::NotFound)
+        ));
+
+        let (base_url, _) = stub_server(500, "");
+        let client = AocClient::new(&base_url, "secret", 2025);
+        assert!(matches!(
+            client.input(day!(1)),
+            Err(AocClientError::BadStatus(500))
+        ));
+    }
+
+    #[test]
+    fn converts_code_blocks() {
+        let html = "<p>Example:</p>\n<pre><code>1 &lt; 2\n<em>3</em> &amp; 4\n</code></pre>\n<ul><li>one</li><li>two</li></ul>";
+        assert_eq!(
+            html_to_markdown(html),
+            "Example:\n\n\n```\n1 < 2\n3 & 4\n```\n\n\n- one\n- two\n"
+        );
+    }
+}
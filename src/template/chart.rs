@@ -0,0 +1,252 @@
+/// Renders benchmark timings as a self-contained SVG bar chart.
+/// Days are drawn as horizontal bars on a logarithmic time axis, so that microsecond and millisecond solutions
+/// can be compared at a glance. Every bar is as long as the total time of the day and split into its
+/// parse phase, part 1 and part 2, proportionally to their share of the total.
+use std::{fmt::Write, time::Duration};
+
+use crate::template::report::PARSE_PART;
+use crate::template::timings::{Timings, format_nanos, parse_duration_nanos};
+
+const WIDTH: f64 = 720.0;
+const MARGIN_LEFT: f64 = 70.0;
+const MARGIN_RIGHT: f64 = 80.0;
+const MARGIN_TOP: f64 = 40.0;
+const MARGIN_BOTTOM: f64 = 30.0;
+const ROW_HEIGHT: f64 = 24.0;
+const BAR_HEIGHT: f64 = 16.0;
+
+/// Segment labels and colors, in the order of [`PARTS`].
+const SEGMENTS: [(&str, &str); 3] = [
+    ("Parse", "#9e9e9e"),
+    ("Part 1", "#4e79a7"),
+    ("Part 2", "#f28e2b"),
+];
+const PARTS: [u8; 3] = [PARSE_PART, 1, 2];
+
+/// The benched time of every segment of a day, in nanoseconds.
+struct Bar {
+    day: u8,
+    nanos: [f64; 3],
+}
+
+impl Bar {
+    fn total(&self) -> f64 {
+        self.nanos.iter().sum()
+    }
+}
+
+/// A logarithmic scale that maps nanoseconds onto the plot area, spanning whole decades.
+struct LogScale {
+    min_decade: i32,
+    max_decade: i32,
+    plot_width: f64,
+}
+
+impl LogScale {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(min_nanos: f64, max_nanos: f64, plot_width: f64) -> Self {
+        let min_decade = min_nanos.log10().floor() as i32;
+        let max_decade = (max_nanos.log10().ceil() as i32).max(min_decade + 1);
+
+        Self {
+            min_decade,
+            max_decade,
+            plot_width,
+        }
+    }
+
+    /// Distance of a time from the start of the axis.
+    fn x(&self, nanos: f64) -> f64 {
+        let span = f64::from(self.max_decade - self.min_decade);
+        (nanos.log10() - f64::from(self.min_decade)) / span * self.plot_width
+    }
+}
+
+/// Render the chart. Returns `None` if no day has a benched part.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn render(timings: &Timings) -> Option<String> {
+    let bars: Vec<Bar> = timings
+        .data
+        .iter()
+        .map(|timing| Bar {
+            day: timing.day.into_inner(),
+            // NOTE: parts that timed out are stored as the limit, e.g. `>10.0s`, and are left out.
+            nanos: PARTS.map(|part| {
+                timing
+                    .part(part)
+                    .0
+                    .and_then(parse_duration_nanos)
+                    .unwrap_or_default()
+            }),
+        })
+        .filter(|bar| bar.total() > 0.0)
+        .collect();
+
+    let min_nanos = bars.iter().map(Bar::total).reduce(f64::min)?;
+    let max_nanos = bars.iter().map(Bar::total).reduce(f64::max)?;
+
+    let plot_width = WIDTH - MARGIN_LEFT - MARGIN_RIGHT;
+    let scale = LogScale::new(min_nanos, max_nanos, plot_width);
+    let plot_height = ROW_HEIGHT * bars.len() as f64;
+    let height = MARGIN_TOP + plot_height + MARGIN_BOTTOM;
+
+    let mut svg = String::new();
+
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{height}" viewBox="0 0 {WIDTH} {height}" font-family="sans-serif" font-size="12">"#
+    );
+    let _ = writeln!(
+        svg,
+        r##"<rect width="{WIDTH}" height="{height}" fill="#ffffff"/>"##
+    );
+
+    // legend
+    for (i, (label, color)) in SEGMENTS.iter().enumerate() {
+        let x = MARGIN_LEFT + i as f64 * 80.0;
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{x}" y="12" width="12" height="12" fill="{color}"/><text x="{}" y="22">{label}</text>"#,
+            x + 16.0
+        );
+    }
+
+    // one gridline per decade
+    for decade in scale.min_decade..=scale.max_decade {
+        let nanos = 10_f64.powi(decade);
+        let x = MARGIN_LEFT + scale.x(nanos);
+        let _ = writeln!(
+            svg,
+            r##"<line x1="{x:.1}" y1="{MARGIN_TOP}" x2="{x:.1}" y2="{:.1}" stroke="#dddddd"/><text x="{x:.1}" y="{:.1}" text-anchor="middle" fill="#666666">{:?}</text>"##,
+            MARGIN_TOP + plot_height,
+            MARGIN_TOP + plot_height + 18.0,
+            Duration::from_nanos(nanos as u64)
+        );
+    }
+
+    for (row, bar) in bars.iter().enumerate() {
+        let y = MARGIN_TOP + row as f64 * ROW_HEIGHT + (ROW_HEIGHT - BAR_HEIGHT) / 2.0;
+        let text_y = y + BAR_HEIGHT - 4.0;
+        let total = bar.total();
+        let bar_width = scale.x(total);
+
+        let _ = writeln!(
+            svg,
+            r#"<text x="{:.1}" y="{text_y:.1}" text-anchor="end">Day {}</text>"#,
+            MARGIN_LEFT - 8.0,
+            bar.day
+        );
+
+        let mut x = MARGIN_LEFT;
+        for (nanos, (label, color)) in bar.nanos.iter().zip(SEGMENTS) {
+            if *nanos <= 0.0 {
+                continue;
+            }
+
+            let width = bar_width * nanos / total;
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{x:.1}" y="{y:.1}" width="{width:.1}" height="{BAR_HEIGHT}" fill="{color}"><title>Day {} {label}: {}</title></rect>"#,
+                bar.day,
+                format_nanos(*nanos)
+            );
+            x += width;
+        }
+
+        let _ = writeln!(
+            svg,
+            r#"<text x="{:.1}" y="{text_y:.1}">{}</text>"#,
+            x + 6.0,
+            format_nanos(total)
+        );
+    }
+
+    svg.push_str("</svg>\n");
+
+    Some(svg)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{LogScale, render};
+    use crate::{
+        day,
+        template::{
+            Day,
+            timings::{Timing, Timings},
+        },
+    };
+    use std::time::Duration;
+
+    fn timing(day: Day, parse: Option<&str>, part_1: Option<&str>, part_2: Option<&str>) -> Timing {
+        Timing {
+            part_1: part_1.map(Into::into),
+            part_2: part_2.map(Into::into),
+            parse: parse.map(Into::into),
+            ..Timing::new(day)
+        }
+    }
+
+    #[test]
+    fn scales_logarithmically() {
+        let scale = LogScale::new(11_000.0, 14_000_000.0, 600.0);
+        assert_eq!(scale.min_decade, 4);
+        assert_eq!(scale.max_decade, 8);
+        assert!((scale.x(10_000.0) - 0.0).abs() < 1e-9);
+        assert!((scale.x(1_000_000.0) - 300.0).abs() < 1e-9);
+        assert!((scale.x(100_000_000.0) - 600.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn spans_at_least_one_decade() {
+        let scale = LogScale::new(1000.0, 1000.0, 600.0);
+        assert_eq!(scale.min_decade, 3);
+        assert_eq!(scale.max_decade, 4);
+    }
+
+    #[test]
+    fn renders_stacked_bars() {
+        let timings = Timings {
+            data: vec![
+                timing(day!(3), None, Some("11.6µs"), Some("13.5µs")),
+                timing(day!(8), Some("1.0ms"), Some("3.7ms"), Some("14.4ms")),
+                timing(day!(12), None, Some("87.3µs"), None),
+            ],
+        };
+
+        let svg = render(&timings).unwrap();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<title>").count(), 6);
+        assert!(svg.contains("<title>Day 8 Parse: 1.0ms</title>"));
+        assert!(svg.contains("<title>Day 3 Part 2: 13.5µs</title>"));
+        assert!(svg.contains(">Day 12</text>"));
+        assert!(svg.contains(">10µs</text>"));
+        assert!(svg.contains(">100ms</text>"));
+    }
+
+    #[test]
+    fn skips_days_without_times() {
+        let mut timed_out = timing(day!(10), None, None, None);
+        timed_out.mark_timed_out(1, Duration::from_secs(10));
+
+        assert!(
+            render(&Timings {
+                data: vec![timed_out.clone()]
+            })
+            .is_none()
+        );
+        assert!(render(&Timings::default()).is_none());
+
+        let timings = Timings {
+            data: vec![timing(day!(1), None, Some("1.0ms"), None), timed_out],
+        };
+        assert!(!render(&timings).unwrap().contains("Day 10"));
+    }
+}
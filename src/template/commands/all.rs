@@ -1,5 +1,25 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{
+    all_days, registry::RegistryEntry, run_multi::run_multi, timeouts::Timeouts,
+};
+
+pub fn handle(
+    is_release: bool,
+    timeouts: &Timeouts,
+    threads: Option<usize>,
+    solutions: &[RegistryEntry],
+) {
+    let summary = run_multi(
+        &all_days().collect(),
+        is_release,
+        false,
+        timeouts,
+        threads,
+        solutions,
+    );
+
+    if summary.mismatches > 0 || summary.failures > 0 {
+        process::exit(1);
+    }
 }
@@ -1,7 +1,22 @@
-use crate::template::{Day, aoc_cli};
+use crate::template::Day;
 use std::process;
 
+#[cfg(feature = "aoc-client")]
 pub fn handle(day: Day) {
+    use crate::template::aoc_client::AocClient;
+
+    let result = AocClient::from_env().and_then(|client| client.download(day));
+
+    if let Err(e) = result {
+        eprintln!("failed to download day {day}: {e}");
+        process::exit(1);
+    }
+}
+
+#[cfg(not(feature = "aoc-client"))]
+pub fn handle(day: Day) {
+    use crate::template::aoc_cli;
+
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -0,0 +1,106 @@
+use std::{fs, process};
+
+use crate::template::puzzle::{PuzzleExamples, expected_literal, fill_test_assertion};
+use crate::template::{Day, solution_path};
+
+pub fn handle(day: Day, overwrite: bool) {
+    let puzzle_path = format!("data/puzzles/{day}.md");
+    let module_path = solution_path(day);
+
+    let Ok(markdown) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Failed to read puzzle description \"{puzzle_path}\". Run `cargo download {day}` to fetch it."
+        );
+        process::exit(1);
+    };
+
+    let examples = PuzzleExamples::parse(&markdown);
+
+    if examples.part_1.input.is_none() {
+        eprintln!("Could not find an example in \"{puzzle_path}\".");
+        return;
+    }
+
+    let part_2_input = examples.distinct_part_2_input();
+
+    write_example(
+        &format!("data/examples/{day}.txt"),
+        examples.part_1.input.as_deref(),
+        overwrite,
+    );
+    write_example(
+        &format!("data/examples/{day}-2.txt"),
+        part_2_input,
+        overwrite,
+    );
+
+    let answers = [
+        (1, examples.part_1.answer.as_deref(), None),
+        (
+            2,
+            examples.part_2.as_ref().and_then(|p| p.answer.as_deref()),
+            part_2_input.map(|_| 2),
+        ),
+    ];
+
+    let Ok(mut module) = fs::read_to_string(&module_path) else {
+        eprintln!("Module file \"{module_path}\" not found, not updating its tests.");
+        return;
+    };
+
+    let mut is_updated = false;
+
+    for (part, answer, example_part) in answers {
+        let Some(answer) = answer else {
+            continue;
+        };
+
+        println!("Expected answer for part {part} of the example: {answer}");
+
+        match fill_test_assertion(&module, part, answer, example_part) {
+            Some(updated) => {
+                module = updated;
+                is_updated = true;
+            }
+            None if module.contains(&format!(
+                "assert_eq!(result, {});",
+                expected_literal(answer)
+            )) => {}
+            None => println!("Test of part {part} was already edited, not updating it."),
+        }
+    }
+
+    if !is_updated {
+        return;
+    }
+
+    match fs::write(&module_path, module) {
+        Ok(()) => println!("Updated tests in \"{module_path}\""),
+        Err(e) => {
+            eprintln!("Failed to update tests: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Write an example file, unless it already has contents and `overwrite` is not set.
+fn write_example(path: &str, input: Option<&str>, overwrite: bool) {
+    let Some(input) = input else {
+        return;
+    };
+
+    let is_empty = fs::read_to_string(path).map_or(true, |s| s.trim().is_empty());
+
+    if !is_empty && !overwrite {
+        println!("Example file \"{path}\" is not empty, use `--overwrite` to replace it.");
+        return;
+    }
+
+    match fs::write(path, input) {
+        Ok(()) => println!("Wrote example to \"{path}\""),
+        Err(e) => {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+}
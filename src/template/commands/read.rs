@@ -1,8 +1,33 @@
 use std::process;
 
-use crate::template::{Day, aoc_cli};
+use crate::template::Day;
 
+#[cfg(feature = "aoc-client")]
 pub fn handle(day: Day) {
+    use crate::template::aoc_client::AocClient;
+    use std::fs;
+
+    let puzzle = AocClient::from_env().and_then(|client| client.puzzle(day));
+
+    match puzzle {
+        Ok(puzzle) => {
+            let path = format!("data/puzzles/{day}.md");
+            if let Err(e) = fs::write(&path, &puzzle) {
+                eprintln!("Failed to write puzzle to \"{path}\": {e}");
+            }
+            println!("{puzzle}");
+        }
+        Err(e) => {
+            eprintln!("failed to read day {day}: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(not(feature = "aoc-client"))]
+pub fn handle(day: Day) {
+    use crate::template::aoc_cli;
+
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -1,13 +1,117 @@
 use std::{
-    fs::{File, OpenOptions},
+    env,
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::PathBuf,
     process,
 };
 
-use crate::template::Day;
+use crate::template::puzzle::{PuzzleExamples, expected_literal};
+use crate::template::{Day, YEAR};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// Environment variable pointing to a directory with user-defined templates.
+pub const TEMPLATE_DIR_ENV: &str = "AOC_TEMPLATE_DIR";
+
+/// The name of the template that is used when none is selected.
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+/// Templates shipped with the repository, selectable by name.
+const BUILTIN_TEMPLATES: &[(&str, &str)] = &[
+    (
+        "default",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/default.txt"
+        )),
+    ),
+    (
+        "grid",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/grid.txt"
+        )),
+    ),
+    (
+        "graph",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/graph.txt"
+        )),
+    ),
+    (
+        "points",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/points.txt"
+        )),
+    ),
+];
+
+/// Directory that user-defined templates are read from. Defaults to `./templates`.
+fn template_dir() -> PathBuf {
+    env::var_os(TEMPLATE_DIR_ENV).map_or_else(|| PathBuf::from("templates"), PathBuf::from)
+}
+
+/// Load a template by name. A user-defined template `<name>.txt` takes precedence over a built-in one.
+fn load_template(name: &str) -> Result<String, String> {
+    let dir = template_dir();
+
+    if let Ok(template) = fs::read_to_string(dir.join(format!("{name}.txt"))) {
+        return Ok(template);
+    }
+
+    if let Some((_, template)) = BUILTIN_TEMPLATES.iter().find(|(n, _)| *n == name) {
+        return Ok((*template).to_string());
+    }
+
+    let mut available: Vec<String> = BUILTIN_TEMPLATES
+        .iter()
+        .map(|(n, _)| (*n).to_string())
+        .collect();
+
+    if let Ok(entries) = fs::read_dir(&dir) {
+        available.extend(entries.filter_map(|entry| {
+            let path = entry.ok()?.path();
+            (path.extension()? == "txt").then(|| path.file_stem()?.to_str().map(String::from))?
+        }));
+    }
+
+    Err(format!(
+        "unknown template \"{name}\". Available templates: {}.",
+        available.join(", ")
+    ))
+}
+
+/// Substitute the placeholders of a template.
+/// The title and expected answers are taken from the puzzle description, if it was downloaded already.
+fn render(template: &str, day: Day, puzzle: Option<&str>) -> String {
+    let examples = puzzle.map(PuzzleExamples::parse).unwrap_or_default();
+
+    let title = puzzle
+        .and_then(PuzzleExamples::title)
+        .unwrap_or_else(|| format!("Day {}", day.into_inner()));
+
+    let answer = |answer: Option<&str>| answer.map_or_else(|| "None".into(), expected_literal);
+
+    // NOTE: the test of part two reads the example of part one. If part two has an example of its own, its answer is
+    // left to `cargo examples`, which also points the test to that example.
+    let part_two_answer = examples
+        .part_2
+        .as_ref()
+        .filter(|_| examples.distinct_part_2_input().is_none())
+        .and_then(|p| p.answer.as_deref());
+
+    template
+        .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+        .replace("%DAY%", &day.to_string())
+        .replace("%YEAR%", &YEAR.map_or_else(String::new, |y| y.to_string()))
+        .replace("%TITLE%", &title)
+        .replace(
+            "%PART_ONE_ANSWER%",
+            &answer(examples.part_1.answer.as_deref()),
+        )
+        .replace("%PART_TWO_ANSWER%", &answer(part_two_answer))
+}
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -19,18 +123,54 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
+/// Create a file if it does not exist, keeping the contents of an existing one (e.g. a downloaded input).
 fn create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new()
         .write(true)
         .create(true)
-        .truncate(true)
+        .truncate(false)
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
+/// Add `pub mod <module>;` to the module list of `src/days/mod.rs`, keeping the modules sorted.
+fn register_module(days_mod: &str, module: &str) -> String {
+    let declaration = format!("pub mod {module};");
+
+    let mut lines: Vec<&str> = days_mod.lines().collect();
+    if !lines.contains(&declaration.as_str()) {
+        lines.push(&declaration);
+    }
+
+    // NOTE: comments and other items keep their position before the sorted declarations.
+    let (mut declarations, other): (Vec<&str>, Vec<&str>) = lines
+        .into_iter()
+        .partition(|line| line.starts_with("pub mod d"));
+    declarations.sort_unstable();
+
+    let mut contents = [other, declarations].concat().join("\n");
+    contents.push('\n');
+    contents
+}
+
+/// Scaffold a solution from a template, creating its input file and `example_count` example files
+/// (`01.txt`, `01-2.txt`, ...).
+/// The solution is a module of the library in `src/days/`, which is run by a binary in `src/bin/`.
+pub fn handle(day: Day, overwrite: bool, template: &str, example_count: u8) {
     let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+    let module_path = format!("src/days/d{day}.rs");
+    let bin_path = format!("src/bin/{day}.rs");
+    let days_mod_path = "src/days/mod.rs";
+    let puzzle_path = format!("data/puzzles/{day}.md");
+
+    let template = match load_template(template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to load template: {e}");
+            process::exit(1);
+        }
+    };
+
+    let puzzle = fs::read_to_string(&puzzle_path).ok();
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,11 +180,7 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(render(&template, day, puzzle.as_deref()).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -54,26 +190,143 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    let days_mod = fs::read_to_string(days_mod_path).unwrap_or_default();
+
+    match fs::write(
+        days_mod_path,
+        register_module(&days_mod, &format!("d{day}")),
+    ) {
+        Ok(()) => {
+            println!("Registered module in \"{days_mod_path}\"");
         }
         Err(e) => {
-            eprintln!("Failed to create input file: {e}");
+            eprintln!("Failed to register module: {e}");
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
+    let bin = format!("advent_of_code::solution!(bin = advent_of_code::days::d{day});\n");
+
+    match safe_create_file(&bin_path, overwrite).and_then(|mut file| file.write_all(bin.as_bytes()))
+    {
+        Ok(()) => {
+            println!("Created binary file \"{}\"", &bin_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create binary file: {e}");
+            process::exit(1);
+        }
+    }
+
+    match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created input file \"{}\"", &input_path);
         }
         Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+            eprintln!("Failed to create input file: {e}");
             process::exit(1);
         }
     }
 
+    for example in 1..=example_count.max(1) {
+        let example_path = if example == 1 {
+            format!("data/examples/{day}.txt")
+        } else {
+            format!("data/examples/{day}-{example}.txt")
+        };
+
+        match create_file(&example_path) {
+            Ok(_) => {
+                println!("Created example file \"{}\"", &example_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
 }
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BUILTIN_TEMPLATES, load_template, register_module, render};
+    use crate::day;
+    use crate::template::puzzle::PuzzleExamples;
+
+    #[test]
+    fn renders_placeholders() {
+        let puzzle = "## --- Day 4: Printing Department ---\n\nThere are `*13*` rolls.\n";
+        let module = render(&load_template("grid").unwrap(), day!(4), Some(puzzle));
+        assert!(module.starts_with("// Day 4: Printing Department\n"));
+        assert!(
+            module.contains("advent_of_code::solution!(4, parse = parse_input -> Grid<char>);")
+        );
+        assert!(module.contains("assert_eq!(result, Some(13));"));
+        assert!(module.contains("assert_eq!(result, None);"));
+        assert!(!module.contains('%'));
+    }
+
+    #[test]
+    fn leaves_answer_of_distinct_part_two_example() {
+        let puzzle = [
+            "## --- Day 4: Printing Department ---",
+            "",
+            "For example:",
+            "",
+            "```",
+            "..@@.",
+            "```",
+            "",
+            "There are `*13*` rolls.",
+            "",
+            "## --- Part Two ---",
+            "",
+            "For example:",
+            "",
+            "```",
+            "@@@..",
+            "```",
+            "",
+            "Now `*43*` rolls are removed.",
+            "",
+        ]
+        .join("\n");
+        let examples = PuzzleExamples::parse(&puzzle);
+        assert_eq!(examples.part_2.unwrap().answer.as_deref(), Some("43"));
+
+        let module = render(&load_template("default").unwrap(), day!(4), Some(&puzzle));
+        assert!(module.contains("assert_eq!(result, Some(13));"));
+        assert!(!module.contains("Some(43)"));
+    }
+
+    #[test]
+    fn renders_without_puzzle() {
+        for (name, template) in BUILTIN_TEMPLATES {
+            let module = render(template, day!(12), None);
+            assert!(module.starts_with("// Day 12\n"), "{name}");
+            assert!(!module.contains('%'), "{name}");
+        }
+    }
+
+    #[test]
+    fn registers_modules_in_order() {
+        let days_mod = "// The solutions of every day.\npub mod d01;\npub mod d03;\n";
+        assert_eq!(
+            register_module(days_mod, "d02"),
+            "// The solutions of every day.\npub mod d01;\npub mod d02;\npub mod d03;\n"
+        );
+        assert_eq!(register_module(days_mod, "d03"), days_mod);
+        assert_eq!(register_module("", "d01"), "pub mod d01;\n");
+    }
+
+    #[test]
+    fn errors_for_unknown_templates() {
+        let err = load_template("does-not-exist").unwrap_err();
+        assert!(err.contains("grid"));
+    }
+}
@@ -1,8 +1,26 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
 use crate::template::Day;
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
+pub fn handle(
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    example: bool,
+    input: Option<&str>,
+    threads: Option<usize>,
+) {
+    if example && input.is_some() {
+        eprintln!("`--example` and `--input` can not be combined.");
+        process::exit(1);
+    }
+
+    if submit_part.is_some() && (example || input.is_some()) {
+        eprintln!("Only results for the puzzle input can be submitted.");
+        process::exit(1);
+    }
+
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
     if dhat {
@@ -16,6 +34,11 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push("--release".to_string());
     }
 
+    // the solution binary submits results itself, so it needs to be built with the same client.
+    if cfg!(feature = "aoc-client") {
+        cmd_args.extend(["--features".to_string(), "aoc-client".to_string()]);
+    }
+
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
@@ -23,8 +46,23 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if example {
+        cmd_args.push("--example".to_string());
+    }
+
+    if let Some(input) = input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input.to_string());
+    }
+
+    if let Some(threads) = threads {
+        cmd_args.push("--threads".to_string());
+        cmd_args.push(threads.to_string());
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
+        .stdin(Stdio::inherit())
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
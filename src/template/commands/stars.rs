@@ -0,0 +1,23 @@
+use std::process;
+
+use crate::template::{LAST_DAY, YEAR, readme_stars};
+
+pub fn handle() {
+    let Some(year) = YEAR else {
+        eprintln!("No year configured, set `AOC_YEAR` in `.cargo/config.toml`.");
+        process::exit(1);
+    };
+
+    match readme_stars::update(year) {
+        Ok(stars) => {
+            println!(
+                "Updated the readme with {stars}/{} ⭐️.",
+                u16::from(LAST_DAY) * 2
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to update the readme: {e:?}");
+            process::exit(1);
+        }
+    }
+}
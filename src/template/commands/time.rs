@@ -1,10 +1,68 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, num::NonZero, process, thread, time::Duration};
 
+use crate::template::export::{self, ExportFormat};
+use crate::template::registry::RegistryEntry;
+use crate::template::report::PARSE_PART;
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::timeouts::Timeouts;
+use crate::template::timings::{TimingDelta, Timings};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, all_days, memory, readme_benchmarks, threads};
+
+/// Slowdown in percent above which a part is reported as a regression, if not configured otherwise.
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 10.0;
+
+/// Options of the `time` command.
+pub struct Options {
+    /// Bench a single day instead of all days that are not fully benched yet.
+    pub day: Option<Day>,
+    /// Bench all days, including the ones that are fully benched.
+    pub run_all: bool,
+    /// Store the timings in `data/timings.json` and the readme.
+    pub store: bool,
+    /// Embed an SVG chart of the timings in the readme when storing.
+    pub chart: bool,
+    pub regression_threshold: f64,
+    pub fail_on_regression: bool,
+    /// Time limits of the parts, see [`run_multi`].
+    pub timeouts: Timeouts,
+    /// Measure the peak heap usage of each day.
+    pub track_memory: bool,
+    /// Export all timings, including the stored ones, to a file.
+    pub export: Option<(ExportFormat, String)>,
+    /// Size of the thread pool solutions run on, the number of cores if not set.
+    pub threads: Option<usize>,
+    /// Bench at an increasing number of threads and report the speedup instead of comparing to stored timings.
+    pub scaling: bool,
+}
+
+pub fn handle(options: &Options, solutions: &[RegistryEntry]) {
+    let Options {
+        day,
+        run_all,
+        store,
+        chart,
+        regression_threshold,
+        fail_on_regression,
+        ref timeouts,
+        track_memory,
+        ref export,
+        threads,
+        scaling,
+    } = *options;
+
+    if track_memory {
+        memory::enable();
+    }
+
+    if scaling {
+        let days_to_run = day.map_or_else(|| all_days().collect(), |day| HashSet::from([day]));
+        let failures = run_scaling(&days_to_run, timeouts, solutions);
+        if failures > 0 {
+            process::exit(1);
+        }
+        return;
+    }
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
     let stored_timings = Timings::read_from_file();
 
     let days_to_run = day.map_or_else(
@@ -21,14 +79,56 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let summary = run_multi(&days_to_run, true, true, timeouts, threads, solutions);
+
+    if summary.mismatches > 0 {
+        eprintln!("Not storing benchmarks of solutions that do not match their confirmed answers.");
+        process::exit(1);
+    }
+
+    let timings = summary.timings.unwrap();
+
+    for timing in &timings.data {
+        if let Some(stored) = stored_timings.data.iter().find(|t| t.day == timing.day)
+            && timing.ran_on_other_threads(stored)
+        {
+            println!(
+                "\nDay {} is not compared to the stored benchmarks, which ran on {} thread(s) instead of {}.",
+                timing.day,
+                stored.threads.unwrap_or_default(),
+                timing.threads.unwrap_or_default()
+            );
+        }
+    }
+
+    let regressions = print_deltas(&timings.compare(&stored_timings), regression_threshold);
+
+    if regressions > 0 && fail_on_regression {
+        eprintln!(
+            "{regressions} part(s) regressed by more than {regression_threshold}% compared to the stored benchmarks."
+        );
+        process::exit(1);
+    }
+
+    let merged_timings = stored_timings.merge(&timings);
+
+    let mut export_failed = false;
+
+    if let Some((format, path)) = export {
+        match export::write(&merged_timings, *format, path) {
+            Ok(()) => println!("\nExported benchmarks to {path}."),
+            Err(e) => {
+                eprintln!("\nFailed to export benchmarks to {path}: {e}");
+                export_failed = true;
+            }
+        }
+    }
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(merged_timings, chart) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -37,4 +137,98 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if summary.failures > 0 || export_failed {
+        process::exit(1);
+    }
+}
+
+/// Bench every day with 1, 2, 4… threads up to the number of cores and print its speedup over a single thread.
+/// Returns the number of failed or mismatching parts.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn run_scaling(
+    days_to_run: &HashSet<Day>,
+    timeouts: &Timeouts,
+    solutions: &[RegistryEntry],
+) -> usize {
+    let max_threads = thread::available_parallelism().map_or(1, NonZero::get);
+    let mut runs: Vec<(usize, Timings)> = vec![];
+    let mut failures = 0;
+
+    for threads in threads::scaling_steps(max_threads) {
+        println!("{ANSI_BOLD}Benching with {threads} thread(s){ANSI_RESET}\n");
+        let summary = run_multi(days_to_run, true, true, timeouts, Some(threads), solutions);
+        failures += summary.failures + summary.mismatches;
+        runs.push((threads, summary.timings.unwrap()));
+        println!();
+    }
+
+    println!("{ANSI_BOLD}Speedup over 1 thread:{ANSI_RESET}");
+
+    let Some((_, baseline)) = runs.first() else {
+        return failures;
+    };
+
+    for timing in baseline.data.iter().filter(|t| t.total_nanos > 0_f64) {
+        let steps: Vec<String> = runs
+            .iter()
+            .filter_map(|(threads, timings)| {
+                let nanos = timings
+                    .data
+                    .iter()
+                    .find(|t| t.day == timing.day && t.total_nanos > 0_f64)?
+                    .total_nanos;
+                let duration = Duration::from_nanos(nanos as u64);
+                Some(if *threads == 1 {
+                    format!("{threads}: {duration:.1?}")
+                } else {
+                    format!(
+                        "{threads}: {duration:.1?} ({:.2}×)",
+                        timing.total_nanos / nanos
+                    )
+                })
+            })
+            .collect();
+
+        println!("Day {}  {}", timing.day, steps.join("  "));
+    }
+
+    failures
+}
+
+/// Print the change of every part compared to the stored timings, highlighting regressions.
+/// Returns the number of regressions.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn print_deltas(deltas: &[TimingDelta], threshold_pct: f64) -> usize {
+    if deltas.is_empty() {
+        return 0;
+    }
+
+    println!("\n{ANSI_BOLD}Compared to stored benchmarks:{ANSI_RESET}");
+
+    let mut regressions = 0;
+
+    for delta in deltas {
+        let before = Duration::from_nanos(delta.before_nanos as u64);
+        let after = Duration::from_nanos(delta.after_nanos as u64);
+        let phase = if delta.part == PARSE_PART {
+            "Parse".into()
+        } else {
+            format!("Part {}", delta.part)
+        };
+        let line = format!(
+            "Day {} {phase}: {before:.1?} → {after:.1?} ({:+.1}%)",
+            delta.day,
+            delta.change_pct()
+        );
+
+        if delta.is_regression(threshold_pct) {
+            regressions += 1;
+            println!("{ANSI_BOLD}▲ {line}{ANSI_RESET}");
+        } else {
+            println!("  {line}");
+        }
+    }
+
+    regressions
 }
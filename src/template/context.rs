@@ -0,0 +1,120 @@
+/// Context passed to part functions that need to know which input they run against.
+use crate::template::InputSource;
+
+/// Describes the input a part function is run against.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Context {
+    pub source: InputSource,
+}
+
+/// A puzzle parameter that differs between the puzzle input and the examples,
+/// e.g. the number of steps to simulate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Param<T> {
+    pub puzzle: T,
+    pub example: T,
+}
+
+impl Context {
+    #[must_use]
+    pub fn new(source: InputSource) -> Self {
+        Self { source }
+    }
+
+    /// Whether the part runs against an example, via `--example` or an `examples!` test.
+    #[must_use]
+    pub fn is_example(&self) -> bool {
+        self.source == InputSource::Example
+    }
+
+    /// The value of a parameter for the current input.
+    /// Inputs passed via `--input` are treated like the puzzle input.
+    #[must_use]
+    pub fn param<T: Copy>(&self, param: &Param<T>) -> T {
+        if self.is_example() {
+            param.example
+        } else {
+            param.puzzle
+        }
+    }
+}
+
+/// A part function, either `fn(&I) -> R` or `fn(&I, &Context) -> R`.
+/// `I` is `str`, or the output of the parse function if the solution has one.
+/// `R` is `Option<T>` or `Result<T, E>`, see [`PartResult`](crate::template::runner::PartResult).
+/// The marker `M` only distinguishes the two signatures and is inferred.
+pub trait Part<I: ?Sized, M> {
+    type Output;
+
+    fn call(&self, input: &I, ctx: &Context) -> Self::Output;
+}
+
+impl<F, I: ?Sized, R> Part<I, fn(&I) -> R> for F
+where
+    F: Fn(&I) -> R,
+{
+    type Output = R;
+
+    fn call(&self, input: &I, _ctx: &Context) -> R {
+        self(input)
+    }
+}
+
+impl<F, I: ?Sized, R> Part<I, fn(&I, &Context) -> R> for F
+where
+    F: Fn(&I, &Context) -> R,
+{
+    type Output = R;
+
+    fn call(&self, input: &I, ctx: &Context) -> R {
+        self(input, ctx)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Context, Param, Part};
+    use crate::template::InputSource;
+
+    const STEPS: Param<u32> = Param {
+        puzzle: 1000,
+        example: 10,
+    };
+
+    fn without_context(input: &str) -> Option<usize> {
+        Some(input.len())
+    }
+
+    fn with_context(_input: &str, ctx: &Context) -> Option<u32> {
+        Some(ctx.param(&STEPS))
+    }
+
+    #[test]
+    fn picks_params_by_input() {
+        assert_eq!(Context::new(InputSource::Puzzle).param(&STEPS), 1000);
+        assert_eq!(Context::new(InputSource::Example).param(&STEPS), 10);
+        assert_eq!(
+            Context::new(InputSource::File("stress.txt".into())).param(&STEPS),
+            1000
+        );
+    }
+
+    fn parsed(input: &[u32]) -> Option<u32> {
+        Some(input.iter().sum())
+    }
+
+    fn fallible(input: &str) -> Result<u32, String> {
+        input.parse().map_err(|_| format!("not a number: {input}"))
+    }
+
+    #[test]
+    fn calls_both_part_signatures() {
+        let ctx = Context::new(InputSource::Example);
+        assert_eq!(Part::call(&without_context, "abc", &ctx), Some(3));
+        assert_eq!(Part::call(&with_context, "abc", &ctx), Some(10));
+        assert_eq!(Part::call(&parsed, &[1, 2][..], &ctx), Some(3));
+        assert_eq!(Part::call(&fallible, "12", &ctx), Ok(12));
+    }
+}
@@ -8,7 +8,44 @@ use chrono::{Datelike, FixedOffset, Utc};
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// The event year configured via the `AOC_YEAR` environment variable at build time, if any.
+pub const YEAR: Option<u16> = parse_year(option_env!("AOC_YEAR"));
+
+/// The last day of the configured event. Defaults to 25 if no year is configured.
+pub const LAST_DAY: u8 = match YEAR {
+    Some(year) => days_in_year(year),
+    None => 25,
+};
+
+/// Returns the number of days of an event: 25 up to 2024, 12 from 2025 on.
+pub const fn days_in_year(year: u16) -> u8 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+const fn parse_year(value: Option<&str>) -> Option<u16> {
+    let Some(value) = value else {
+        return None;
+    };
+
+    let bytes = value.as_bytes();
+    if bytes.is_empty() || bytes.len() > 4 {
+        return None;
+    }
+
+    let mut year: u16 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        year = year * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+
+    Some(year)
+}
+
+/// A valid day number of advent (i.e. an integer in range 1 to [`LAST_DAY`]).
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -25,7 +62,7 @@ impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
     /// returns [`None`] otherwise.
     pub const fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        if day == 0 || day > LAST_DAY {
             return None;
         }
         Some(Self(day))
@@ -39,11 +76,11 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's between the 1st and the last day of the event in december, `None` otherwise.
     pub fn today() -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
+        if today.month() == 12 && today.day() <= u32::from(LAST_DAY) {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
@@ -88,18 +125,24 @@ impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        match YEAR {
+            Some(year) => write!(
+                f,
+                "expecting a day number between 1 and {LAST_DAY} (the {year} event has {LAST_DAY} days)"
+            ),
+            None => write!(f, "expecting a day number between 1 and {LAST_DAY}"),
+        }
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent from the 1st to the last day of the event.
 pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent from the 1st to the last day of the event.
 pub struct AllDays {
     current: u8,
 }
@@ -115,10 +158,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > LAST_DAY {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and we have verified that the value is not above the last day.
         let day = Day(self.current);
         self.current += 1;
 
@@ -133,8 +176,9 @@ impl Iterator for AllDays {
 macro_rules! day {
     ($day:expr) => {
         const {
-            $crate::template::Day::new($day)
-                .expect("invalid day number, expecting a value between 1 and 25")
+            $crate::template::Day::new($day).expect(
+                "invalid day number, expecting a value between 1 and the last day of the event",
+            )
         }
     };
 }
@@ -143,39 +187,40 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{Day, all_days};
+    use super::{Day, LAST_DAY, all_days, days_in_year, parse_year};
 
     #[test]
     fn all_days_iterator() {
         let mut iter = all_days();
 
-        assert_eq!(iter.next(), Some(Day(1)));
-        assert_eq!(iter.next(), Some(Day(2)));
-        assert_eq!(iter.next(), Some(Day(3)));
-        assert_eq!(iter.next(), Some(Day(4)));
-        assert_eq!(iter.next(), Some(Day(5)));
-        assert_eq!(iter.next(), Some(Day(6)));
-        assert_eq!(iter.next(), Some(Day(7)));
-        assert_eq!(iter.next(), Some(Day(8)));
-        assert_eq!(iter.next(), Some(Day(9)));
-        assert_eq!(iter.next(), Some(Day(10)));
-        assert_eq!(iter.next(), Some(Day(11)));
-        assert_eq!(iter.next(), Some(Day(12)));
-        assert_eq!(iter.next(), Some(Day(13)));
-        assert_eq!(iter.next(), Some(Day(14)));
-        assert_eq!(iter.next(), Some(Day(15)));
-        assert_eq!(iter.next(), Some(Day(16)));
-        assert_eq!(iter.next(), Some(Day(17)));
-        assert_eq!(iter.next(), Some(Day(18)));
-        assert_eq!(iter.next(), Some(Day(19)));
-        assert_eq!(iter.next(), Some(Day(20)));
-        assert_eq!(iter.next(), Some(Day(21)));
-        assert_eq!(iter.next(), Some(Day(22)));
-        assert_eq!(iter.next(), Some(Day(23)));
-        assert_eq!(iter.next(), Some(Day(24)));
-        assert_eq!(iter.next(), Some(Day(25)));
+        for day in 1..=LAST_DAY {
+            assert_eq!(iter.next(), Some(Day(day)));
+        }
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn days_per_event() {
+        assert_eq!(days_in_year(2015), 25);
+        assert_eq!(days_in_year(2024), 25);
+        assert_eq!(days_in_year(2025), 12);
+    }
+
+    #[test]
+    fn parses_years() {
+        assert_eq!(parse_year(Some("2025")), Some(2025));
+        assert_eq!(parse_year(Some("20x5")), None);
+        assert_eq!(parse_year(Some("")), None);
+        assert_eq!(parse_year(None), None);
+    }
+
+    #[test]
+    fn rejects_days_after_the_event() {
+        assert_eq!(Day::new(LAST_DAY), Some(Day(LAST_DAY)));
+        assert_eq!(Day::new(LAST_DAY + 1), None);
+        assert_eq!(Day::new(0), None);
+        assert!("26".parse::<Day>().is_err());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
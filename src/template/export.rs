@@ -0,0 +1,340 @@
+/// Export of benchmark timings for spreadsheets and dashboards.
+/// Every format contains one row per benched part (and parse phase), with its statistics in nanoseconds.
+use std::{fmt::Write, fs, io, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    YEAR, memory,
+    report::PARSE_PART,
+    timings::{PartStats, Timing, Timings, format_nanos, parse_duration_nanos},
+};
+
+/// A file format timings can be exported to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    JsonLines,
+    Markdown,
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Self::Csv),
+            "jsonl" => Ok(Self::JsonLines),
+            "md" | "markdown" => Ok(Self::Markdown),
+            _ => Err(format!(
+                "unknown export format `{s}`, expected `csv`, `jsonl` or `md`."
+            )),
+        }
+    }
+}
+
+/// Columns of the CSV export, and keys of the JSON Lines export.
+const COLUMNS: [&str; 15] = [
+    "year",
+    "day",
+    "part",
+    "time",
+    "mean_nanos",
+    "median_nanos",
+    "std_dev_nanos",
+    "min_nanos",
+    "max_nanos",
+    "p95_nanos",
+    "samples",
+    "outliers",
+    "timed_out",
+    "peak_bytes",
+    "threads",
+];
+
+/// A single benched part of a day.
+struct Row<'a> {
+    timing: &'a Timing,
+    part: u8,
+    time: &'a str,
+    stats: Option<&'a PartStats>,
+}
+
+impl Row<'_> {
+    fn part_name(&self) -> &'static str {
+        match self.part {
+            PARSE_PART => "parse",
+            1 => "part_1",
+            _ => "part_2",
+        }
+    }
+
+    fn is_timed_out(&self) -> bool {
+        self.timing.timed_out == Some(self.part)
+    }
+
+    /// The exact mean if it was stored, the rounded display string otherwise.
+    fn mean_nanos(&self) -> Option<f64> {
+        self.stats
+            .and_then(|s| s.mean_nanos)
+            .or_else(|| parse_duration_nanos(self.time))
+    }
+
+    /// The values of the row, in the order of [`COLUMNS`].
+    #[allow(clippy::cast_precision_loss)]
+    fn values(&self) -> [JsonValue; COLUMNS.len()] {
+        let number = |n: Option<f64>| n.map_or(JsonValue::Null, JsonValue::Number);
+        let stat = |f: fn(&PartStats) -> f64| number(self.stats.map(f));
+
+        [
+            number(YEAR.map(f64::from)),
+            JsonValue::String(self.timing.day.to_string()),
+            JsonValue::String(self.part_name().into()),
+            JsonValue::String(self.time.into()),
+            number(self.mean_nanos()),
+            stat(|s| s.median_nanos),
+            stat(|s| s.std_dev_nanos),
+            stat(|s| s.min_nanos),
+            stat(|s| s.max_nanos),
+            stat(|s| s.p95_nanos),
+            stat(|s| s.samples as f64),
+            stat(|s| s.outliers as f64),
+            JsonValue::Boolean(self.is_timed_out()),
+            number(self.timing.peak_bytes.map(|b| b as f64)),
+            number(self.timing.threads.map(|t| t as f64)),
+        ]
+    }
+}
+
+fn rows(timings: &Timings) -> Vec<Row<'_>> {
+    timings
+        .data
+        .iter()
+        .flat_map(|timing| {
+            [PARSE_PART, 1, 2].into_iter().filter_map(move |part| {
+                let (time, stats) = timing.part(part);
+                time.map(|time| Row {
+                    timing,
+                    part,
+                    time,
+                    stats,
+                })
+            })
+        })
+        .collect()
+}
+
+/// Render timings in the given format.
+pub(crate) fn render(timings: &Timings, format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Csv => render_csv(timings),
+        ExportFormat::JsonLines => render_json_lines(timings),
+        ExportFormat::Markdown => render_markdown(timings),
+    }
+}
+
+/// Write timings in the given format to `path`.
+pub(crate) fn write(timings: &Timings, format: ExportFormat, path: &str) -> Result<(), io::Error> {
+    fs::write(path, render(timings, format))
+}
+
+fn render_csv(timings: &Timings) -> String {
+    let mut csv = COLUMNS.join(",");
+    csv.push('\n');
+
+    for row in rows(timings) {
+        let values: Vec<String> = row.values().iter().map(csv_value).collect();
+        csv.push_str(&values.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+fn csv_value(value: &JsonValue) -> String {
+    match value {
+        JsonValue::Number(n) => n.to_string(),
+        JsonValue::Boolean(b) => b.to_string(),
+        JsonValue::String(s) if s.contains([',', '"', '\n']) => {
+            format!("\"{}\"", s.replace('"', "\"\""))
+        }
+        JsonValue::String(s) => s.clone(),
+        _ => String::new(),
+    }
+}
+
+fn render_json_lines(timings: &Timings) -> String {
+    rows(timings)
+        .iter()
+        .map(|row| {
+            let object = COLUMNS
+                .iter()
+                .map(|column| (*column).to_string())
+                .zip(row.values())
+                .collect();
+
+            // NOTE: stringifying a JSON object of strings, numbers and booleans can not fail.
+            JsonValue::Object(object).stringify().unwrap() + "\n"
+        })
+        .collect()
+}
+
+fn render_markdown(timings: &Timings) -> String {
+    let stat = |stats: Option<&PartStats>, f: fn(&PartStats) -> String| {
+        stats.map_or_else(|| "-".into(), f)
+    };
+
+    let mut md = match YEAR {
+        Some(year) => format!("# Advent of Code {year} Benchmarks\n\n"),
+        None => "# Advent of Code Benchmarks\n\n".into(),
+    };
+
+    md.push_str(
+        "| Day | Part | Mean | Median | Std. dev. | Min | Max | p95 | Samples | Outliers | Memory | Threads |\n",
+    );
+    md.push_str(
+        "| :---: | :---: | ---: | ---: | ---: | ---: | ---: | ---: | ---: | ---: | ---: | ---: |\n",
+    );
+
+    for row in rows(timings) {
+        let part = match row.part {
+            PARSE_PART => "Parse".into(),
+            part => format!("Part {part}"),
+        };
+
+        let time = if row.is_timed_out() {
+            format!("{} (timed out)", row.time)
+        } else {
+            row.time.into()
+        };
+
+        let _ = writeln!(
+            md,
+            "| {} | {part} | {time} | {} | {} | {} | {} | {} | {} | {} | {} | {} |",
+            row.timing.day.into_inner(),
+            stat(row.stats, |s| format_nanos(s.median_nanos)),
+            stat(row.stats, |s| format_nanos(s.std_dev_nanos)),
+            stat(row.stats, |s| format_nanos(s.min_nanos)),
+            stat(row.stats, |s| format_nanos(s.max_nanos)),
+            stat(row.stats, |s| format_nanos(s.p95_nanos)),
+            stat(row.stats, |s| s.samples.to_string()),
+            stat(row.stats, |s| s.outliers.to_string()),
+            row.timing
+                .peak_bytes
+                .map_or_else(|| "-".into(), memory::format_bytes),
+            row.timing
+                .threads
+                .map_or_else(|| "-".into(), |t| t.to_string()),
+        );
+    }
+
+    let _ = write!(md, "\n**Total: {:.2}ms**\n", timings.total_millis());
+
+    md
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ExportFormat, render};
+    use crate::{
+        day,
+        template::{
+            YEAR,
+            timings::{PartStats, Timing, Timings},
+        },
+    };
+    use std::{str::FromStr, time::Duration};
+    use tinyjson::JsonValue;
+
+    fn get_mock_timings() -> Timings {
+        let mut timed_out = Timing {
+            part_1: Some("1.5ms".into()),
+            total_nanos: 1_500_000_f64,
+            ..Timing::new(day!(10))
+        };
+        timed_out.mark_timed_out(2, Duration::from_secs(10));
+
+        Timings {
+            data: vec![
+                Timing {
+                    part_1: Some("2.0µs".into()),
+                    part_2: Some("10ms".into()),
+                    part_1_stats: Some(PartStats {
+                        mean_nanos: Some(2012.5),
+                        median_nanos: 1900.0,
+                        std_dev_nanos: 100.0,
+                        min_nanos: 1500.0,
+                        max_nanos: 3000.0,
+                        p95_nanos: 2500.0,
+                        samples: 10000,
+                        outliers: 12,
+                    }),
+                    parse: Some("500.0ns".into()),
+                    peak_bytes: Some(2048),
+                    threads: Some(8),
+                    total_nanos: 10_002_500_f64,
+                    ..Timing::new(day!(1))
+                },
+                timed_out,
+            ],
+        }
+    }
+
+    fn year() -> String {
+        YEAR.map(|y| y.to_string()).unwrap_or_default()
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!(ExportFormat::from_str("csv"), Ok(ExportFormat::Csv));
+        assert_eq!(ExportFormat::from_str("jsonl"), Ok(ExportFormat::JsonLines));
+        assert_eq!(ExportFormat::from_str("md"), Ok(ExportFormat::Markdown));
+        assert!(ExportFormat::from_str("xlsx").is_err());
+    }
+
+    #[test]
+    fn exports_csv() {
+        let csv = render(&get_mock_timings(), ExportFormat::Csv);
+        let year = year();
+        let expected = [
+            "year,day,part,time,mean_nanos,median_nanos,std_dev_nanos,min_nanos,max_nanos,p95_nanos,samples,outliers,timed_out,peak_bytes,threads".to_string(),
+            format!("{year},01,parse,500.0ns,500,,,,,,,,false,2048,8"),
+            format!("{year},01,part_1,2.0µs,2012.5,1900,100,1500,3000,2500,10000,12,false,2048,8"),
+            format!("{year},01,part_2,10ms,10000000,,,,,,,,false,2048,8"),
+            format!("{year},10,part_1,1.5ms,1500000,,,,,,,,false,,"),
+            format!("{year},10,part_2,>10.0s,,,,,,,,,true,,"),
+        ]
+        .join("\n")
+            + "\n";
+        assert_eq!(csv, expected);
+    }
+
+    #[test]
+    fn exports_json_lines() {
+        let jsonl = render(&get_mock_timings(), ExportFormat::JsonLines);
+        let lines: Vec<JsonValue> = jsonl.lines().map(|l| l.parse().unwrap()).collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[1]["day"], JsonValue::String("01".into()));
+        assert_eq!(lines[1]["part"], JsonValue::String("part_1".into()));
+        assert_eq!(lines[1]["median_nanos"], JsonValue::Number(1900.0));
+        assert_eq!(lines[1]["samples"], JsonValue::Number(10000.0));
+        assert_eq!(lines[1]["peak_bytes"], JsonValue::Number(2048.0));
+        assert_eq!(lines[1]["threads"], JsonValue::Number(8.0));
+        assert_eq!(lines[4]["threads"], JsonValue::Null);
+        assert_eq!(lines[4]["timed_out"], JsonValue::Boolean(true));
+        assert_eq!(lines[4]["mean_nanos"], JsonValue::Null);
+    }
+
+    #[test]
+    fn exports_markdown() {
+        let md = render(&get_mock_timings(), ExportFormat::Markdown);
+        assert!(md.starts_with("# Advent of Code"));
+        assert!(md.contains(
+            "| 1 | Part 1 | 2.0µs | 1.9µs | 100.0ns | 1.5µs | 3.0µs | 2.5µs | 10000 | 12 | 2.0 KiB | 8 |"
+        ));
+        assert!(md.contains("| 10 | Part 2 | >10.0s (timed out) | - |"));
+        assert!(md.ends_with("**Total: 11.50ms**\n"));
+    }
+}
@@ -0,0 +1,181 @@
+/// Resolves the input a solution binary is run against.
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::template::Day;
+
+/// The source a solution reads its input from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/inputs`.
+    Puzzle,
+    /// The example input in `data/examples`.
+    Example,
+    /// An explicit file path.
+    File(PathBuf),
+    /// Standard input, selected with `--input -`.
+    Stdin,
+}
+
+impl InputSource {
+    /// Parse the input source from the `--example` and `--input <path>` arguments of a solution binary.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let is_example = args.iter().any(|x| x == "--example");
+
+        let path = match args.iter().position(|x| x == "--input") {
+            Some(index) => Some(
+                args.get(index + 1)
+                    .ok_or("`--input` expects a file path or `-` for stdin.")?,
+            ),
+            None => None,
+        };
+
+        match (is_example, path) {
+            (true, Some(_)) => Err("`--example` and `--input` can not be combined.".into()),
+            (true, None) => Ok(Self::Example),
+            (false, Some(path)) if path == "-" => Ok(Self::Stdin),
+            (false, Some(path)) => Ok(Self::File(path.into())),
+            (false, None) => Ok(Self::Puzzle),
+        }
+    }
+
+    /// Read the input for a day from this source.
+    pub fn read(&self, day: Day) -> Result<String, ReadFileError> {
+        match self {
+            Self::Puzzle => read_path(&data_path("inputs", &format!("{day}.txt"))),
+            Self::Example => read_path(&data_path("examples", &format!("{day}.txt"))),
+            Self::File(path) => read_path(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| ReadFileError {
+                        path: PathBuf::from("<stdin>"),
+                        source,
+                    })?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Read the input of a single part, if it has one of its own: the example file of the part, e.g. `01-2.txt`,
+    /// like [`read_file_part`](crate::template::read_file_part) reads it. `None` for parts that use the input of the
+    /// day, which includes every part of the other sources.
+    pub fn read_part(&self, day: Day, part: u8) -> Option<Result<String, ReadFileError>> {
+        if *self != Self::Example {
+            return None;
+        }
+
+        match read_path(&data_path("examples", &format!("{day}-{part}.txt"))) {
+            Err(e) if e.source.kind() == io::ErrorKind::NotFound => None,
+            result => Some(result),
+        }
+    }
+}
+
+/// Path of a file in the `data` folder of the current working directory.
+pub(crate) fn data_path(folder: &str, file_name: &str) -> PathBuf {
+    let cwd = env::current_dir().unwrap();
+    cwd.join("data").join(folder).join(file_name)
+}
+
+pub(crate) fn read_path(path: &Path) -> Result<String, ReadFileError> {
+    fs::read_to_string(path).map_err(|source| ReadFileError {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// An error which can be returned when reading an input file.
+#[derive(Debug)]
+pub struct ReadFileError {
+    pub path: PathBuf,
+    pub source: io::Error,
+}
+
+impl Error for ReadFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl Display for ReadFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "could not read input file \"{}\": {}",
+            self.path.display(),
+            self.source
+        )?;
+
+        if self.source.kind() == io::ErrorKind::NotFound {
+            let folder = self.path.parent().and_then(Path::file_name);
+            if folder.is_some_and(|f| f == "inputs") {
+                write!(f, " (run `cargo download <day>` to fetch it)")?;
+            } else if folder.is_some_and(|f| f == "examples") {
+                write!(f, " (paste the example from the puzzle description)")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn parses_default_source() {
+        let source = InputSource::from_args(&args(&["target/debug/01"]));
+        assert_eq!(source, Ok(InputSource::Puzzle));
+    }
+
+    #[test]
+    fn parses_example_source() {
+        let source = InputSource::from_args(&args(&["01", "--time", "--example"]));
+        assert_eq!(source, Ok(InputSource::Example));
+    }
+
+    #[test]
+    fn parses_file_and_stdin_sources() {
+        let source = InputSource::from_args(&args(&["01", "--input", "stress.txt"]));
+        assert_eq!(source, Ok(InputSource::File("stress.txt".into())));
+        let source = InputSource::from_args(&args(&["01", "--input", "-"]));
+        assert_eq!(source, Ok(InputSource::Stdin));
+    }
+
+    #[test]
+    fn errors_for_invalid_sources() {
+        assert!(InputSource::from_args(&args(&["01", "--input"])).is_err());
+        assert!(InputSource::from_args(&args(&["01", "--example", "--input", "-"])).is_err());
+    }
+
+    #[test]
+    fn reads_examples_of_parts() {
+        let part_two = InputSource::Example.read_part(crate::day!(11), 2);
+        assert!(part_two.unwrap().unwrap().starts_with("svr:"));
+        assert!(InputSource::Example.read_part(crate::day!(11), 1).is_none());
+        assert!(InputSource::Puzzle.read_part(crate::day!(11), 2).is_none());
+    }
+
+    #[test]
+    fn reports_missing_files() {
+        let err = InputSource::File("does/not/exist.txt".into())
+            .read(crate::day!(1))
+            .unwrap_err();
+        assert!(err.to_string().contains("does/not/exist.txt"));
+    }
+}
@@ -0,0 +1,166 @@
+/// Peak heap measurement for `cargo time --memory`.
+/// Solution binaries and the main binary install [`PeakAlloc`] as their global allocator, which counts allocated
+/// bytes while tracking is enabled. The library itself does not, so crates that use it keep their own allocator.
+/// When built with the `dhat-heap` feature, DHAT provides the global allocator and nothing is measured.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicBool, AtomicIsize, Ordering},
+};
+
+// NOTE: the unit tests of the library are a binary of their own, which measures allocations as well.
+#[cfg(all(test, feature = "test_lib", not(feature = "dhat-heap")))]
+#[global_allocator]
+static ALLOCATOR: PeakAlloc = PeakAlloc;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Bytes currently allocated. Signed, as memory allocated before tracking was enabled may be freed.
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static BASELINE: AtomicIsize = AtomicIsize::new(0);
+
+/// The system allocator, counting the allocated bytes and their peak while tracking is enabled.
+pub struct PeakAlloc;
+
+impl PeakAlloc {
+    #[allow(clippy::cast_possible_wrap)]
+    fn record(size: usize, is_alloc: bool) {
+        if !ENABLED.load(Ordering::Relaxed) {
+            return;
+        }
+
+        if is_alloc {
+            let current = CURRENT.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+            PEAK.fetch_max(current, Ordering::Relaxed);
+        } else {
+            CURRENT.fetch_sub(size as isize, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for PeakAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::record(layout.size(), true);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::record(layout.size(), true);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Self::record(layout.size(), false);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            Self::record(layout.size(), false);
+            Self::record(new_size, true);
+        }
+        new_ptr
+    }
+}
+
+/// Start counting allocations.
+pub fn enable() {
+    ENABLED.store(true, Ordering::SeqCst);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::SeqCst)
+}
+
+/// Stop counting allocations until the returned guard is dropped.
+/// Memory allocated while paused must be freed before the guard is dropped.
+pub fn pause() -> Paused {
+    Paused {
+        was_enabled: ENABLED.swap(false, Ordering::SeqCst),
+    }
+}
+
+/// Guard that resumes counting allocations when dropped, see [`pause`].
+pub struct Paused {
+    was_enabled: bool,
+}
+
+impl Drop for Paused {
+    fn drop(&mut self) {
+        ENABLED.store(self.was_enabled, Ordering::SeqCst);
+    }
+}
+
+/// Start a new measurement. Memory that is already allocated does not count towards the peak.
+pub fn reset() {
+    let current = CURRENT.load(Ordering::SeqCst);
+    BASELINE.store(current, Ordering::SeqCst);
+    PEAK.store(current, Ordering::SeqCst);
+}
+
+/// Peak number of bytes allocated since the last [`reset`], or `None` if tracking is not enabled.
+#[allow(clippy::cast_sign_loss)]
+pub fn peak_bytes() -> Option<u64> {
+    if cfg!(feature = "dhat-heap") || !is_enabled() {
+        return None;
+    }
+
+    let peak = PEAK.load(Ordering::SeqCst) - BASELINE.load(Ordering::SeqCst);
+    Some(peak.max(0) as u64)
+}
+
+/// Format a number of bytes with a binary unit, e.g. `1.5 MiB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024_f64;
+    let mut unit = 0;
+
+    while value >= 1024_f64 && unit < UNITS.len() - 1 {
+        value /= 1024_f64;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{enable, format_bytes, peak_bytes, reset};
+    use std::hint::black_box;
+
+    #[test]
+    fn measures_peak_allocations() {
+        enable();
+        reset();
+
+        let buffer = black_box(vec![0_u8; 4 << 20]);
+        drop(buffer);
+        let small = black_box(vec![0_u8; 1024]);
+
+        let peak = peak_bytes().unwrap();
+        assert!(peak >= 4 << 20, "peak of {peak} bytes is too low");
+        drop(small);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+        assert_eq!(format_bytes(5 << 30), "5.0 GiB");
+    }
+}
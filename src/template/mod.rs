@@ -1,14 +1,33 @@
-use std::{env, fs};
+use std::{env, process};
 
 pub mod aoc_cli;
+#[cfg(feature = "aoc-client")]
+pub mod aoc_client;
 pub mod commands;
+pub mod context;
+pub mod export;
+pub mod input;
+pub mod memory;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod solution;
+pub mod threads;
+pub mod timeouts;
 
+pub use context::{Context, Param, Part};
 pub use day::*;
+pub use input::{InputSource, ReadFileError};
+pub use solution::Solution;
 
+mod answers;
+mod chart;
 mod day;
+mod puzzle;
 mod readme_benchmarks;
+mod readme_stars;
 mod run_multi;
+mod submissions;
 mod timings;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
@@ -16,31 +35,197 @@ pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
+///
+/// # Panics
+/// Panics with a descriptive message if the file can not be read, see [`try_read_file`].
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    try_read_file(folder, day).unwrap_or_else(|e| panic!("{e}"))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+///
+/// # Panics
+/// Panics with a descriptive message if the file can not be read, see [`try_read_file_part`].
 #[must_use]
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    try_read_file_part(folder, day, part).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Helper function that reads a text file to a string, returning an error if it can not be read.
+pub fn try_read_file(folder: &str, day: Day) -> Result<String, ReadFileError> {
+    input::read_path(&input::data_path(folder, &format!("{day}.txt")))
+}
+
+/// Helper function that reads a text file with a part suffix to a string, returning an error if it can not be read.
+pub fn try_read_file_part(folder: &str, day: Day, part: u8) -> Result<String, ReadFileError> {
+    input::read_path(&input::data_path(folder, &format!("{day}-{part}.txt")))
+}
+
+/// Path of the source file with the logic of a day: its module in `src/days/`, or its binary if it has none.
+#[must_use]
+pub fn solution_path(day: Day) -> String {
+    let module = format!("src/days/d{day}.rs");
+
+    if std::path::Path::new(&module).exists() {
+        module
+    } else {
+        format!("src/bin/{day}.rs")
+    }
+}
+
+/// Helper function that reads an example file by its name, e.g. `01-2.txt`.
+///
+/// # Panics
+/// Panics with a descriptive message if the file can not be read.
+#[must_use]
+pub fn read_example(file_name: &str) -> String {
+    input::read_path(&input::data_path("examples", file_name)).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Names of the example files of a day (`01.txt`, `01-2.txt`, ...), sorted.
+#[must_use]
+pub fn example_files(day: Day) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(input::data_path("examples", "")) else {
+        return vec![];
+    };
+
+    let prefix = day.to_string();
+
+    let mut files: Vec<String> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| {
+            name.strip_prefix(&prefix).is_some_and(|rest| {
+                rest == ".txt" || (rest.starts_with('-') && rest.ends_with(".txt"))
+            })
+        })
+        .collect();
+
+    files.sort_unstable();
+    files
+}
+
+/// Names of the example files of a day (`01.txt`, `01-2.txt`, ...) that are not in `declared`.
+#[must_use]
+pub fn undeclared_examples(day: Day, declared: &[&str]) -> Vec<String> {
+    example_files(day)
+        .into_iter()
+        .filter(|name| !declared.contains(&name.as_str()))
+        .collect()
+}
+
+/// Reads the input for a solution binary from the source selected on the command-line
+/// (`--example`, `--input <path>` or `--input -` for stdin, the puzzle input otherwise).
+/// Exits the process with an error message if the input can not be read.
+#[must_use]
+pub fn read_input(day: Day) -> String {
+    read_input_with_context(day).0
+}
+
+/// Same as [`read_input`], also returning the context that tells part functions which input is used.
+#[must_use]
+pub fn read_input_with_context(day: Day) -> (String, Context) {
+    let args: Vec<String> = env::args().collect();
+
+    let input = InputSource::from_args(&args)
+        .map_err(|e| e.to_string())
+        .and_then(|source| {
+            let input = source.read(day).map_err(|e| e.to_string())?;
+            Ok((input, Context::new(source)))
+        });
+
+    match input {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        }
+    }
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Generates a test for every part of every example file, comparing its result with the declared answer.
+/// Each example becomes a module, so the tests are named e.g. `tests::example_2::part_two`.
+/// Parts can be `#[ignore]`d individually.
+/// Also generates the test `examples_are_declared`, which fails if an example file of the day has no declared answers.
+///
+/// Must be invoked in a module where `DAY` and the part functions are in scope, e.g. the `tests` module of a solution:
+/// ```ignore
+/// advent_of_code::examples! {
+///     example("01.txt") { part_one: Some(142) }
+///     example_2("01-2.txt") { part_two: Some(281) }
+/// }
+/// ```
+///
+/// Solutions with a `parse` function pass it first, e.g. `advent_of_code::examples! { parse = parse_input; ... }`.
+#[macro_export]
+macro_rules! examples {
+    ($( $name:ident($file:literal) { $( $(#[$meta:meta])* $part:ident: $expected:expr ),* $(,)? } )*) => {
+        $crate::examples!(@impl ::std::convert::identity; $( $name($file) { $( $(#[$meta])* $part: $expected ),* } )*);
+    };
+    (parse = $parse:ident; $( $name:ident($file:literal) { $( $(#[$meta:meta])* $part:ident: $expected:expr ),* $(,)? } )*) => {
+        $crate::examples!(@impl super::$parse; $( $name($file) { $( $(#[$meta])* $part: $expected ),* } )*);
+    };
+
+    (@impl $parse:expr; $( $name:ident($file:literal) { $( $(#[$meta:meta])* $part:ident: $expected:expr ),* } )*) => {
+        $(
+            mod $name {
+                $(
+                    $(#[$meta])*
+                    #[test]
+                    fn $part() {
+                        let input = $crate::template::read_example($file);
+                        let parsed = ($parse)(input.as_str());
+                        let ctx = $crate::template::Context::new($crate::template::InputSource::Example);
+                        assert_eq!($crate::template::Part::call(&super::$part, &parsed, &ctx), $expected);
+                    }
+                )*
+            }
+        )*
+
+        #[test]
+        fn examples_are_declared() {
+            let undeclared = $crate::template::undeclared_examples(DAY, &[$( $file ),*]);
+            assert!(
+                undeclared.is_empty(),
+                "example files without declared answers: {undeclared:?}"
+            );
+        }
+    };
+}
+
+/// Creates the constant `DAY` and implements [`Solution`] for the unit struct `DaySolution`, which calls the part
+/// functions of the day. Also creates the constant `SOLUTION`, which registers the solution with the in-process
+/// solution registry.
+///
+/// Solutions live in a module of the library, e.g. `advent_of_code::days::d01`. Their binary only consists of
+/// `advent_of_code::solution!(bin = advent_of_code::days::d01);`, which generates the `main` that reads the input
+/// and runs every part, and installs the global allocator.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Part functions take the input, and optionally a [`Context`] describing which input is used.
+///
+/// Part functions return `Option<T>`, or `Result<T, E>` with a displayable error. Errors and panics are
+/// printed in place of the answer.
+///
+/// With the optional `parse = <fn> -> <type>` parameter, the input is parsed once by that function and both parts
+/// take a reference to its output instead of the input. The type is the output of the parse function and becomes
+/// [`Solution::Parsed`]; borrows from the input use the lifetime `'a`, e.g. `parse = parse_input -> Vec<&'a str>`.
+/// Parsing is timed separately from the parts. If parsing panics, the parts are not run.
 #[macro_export]
 macro_rules! solution {
+    (bin = $($module:ident)::+) => {
+        #[cfg(not(feature = "dhat-heap"))]
+        #[global_allocator]
+        static ALLOCATOR: $crate::template::memory::PeakAlloc = $crate::template::memory::PeakAlloc;
+
+        #[cfg(feature = "dhat-heap")]
+        #[global_allocator]
+        static ALLOC: dhat::Alloc = dhat::Alloc;
+
+        fn main() {
+            $crate::template::runner::run_solution::<$($module)::+::DaySolution>();
+        }
+    };
     ($day:expr) => {
         $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
     };
@@ -50,19 +235,79 @@ macro_rules! solution {
     ($day:expr, 2) => {
         $crate::solution!(@impl $day, [part_two, 2]);
     };
+    ($day:expr, parse = $parse:ident $(:: $path:ident)* -> $parsed:ty) => {
+        $crate::solution!(@impl_parse $day, $parse $(:: $path)*, $parsed, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, 1, parse = $parse:ident $(:: $path:ident)* -> $parsed:ty) => {
+        $crate::solution!(@impl_parse $day, $parse $(:: $path)*, $parsed, [part_one, 1]);
+    };
+    ($day:expr, 2, parse = $parse:ident $(:: $path:ident)* -> $parsed:ty) => {
+        $crate::solution!(@impl_parse $day, $parse $(:: $path)*, $parsed, [part_two, 2]);
+    };
+    ($day:expr, $(1,)? $(2,)? parse = $parse:expr) => {
+        compile_error!(
+            "`parse` expects the type of the parsed input as well, e.g. `parse = parse_input -> Vec<u64>`."
+        );
+    };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $day:expr, $( [$func:ident, $part:expr] )*) => {
+        $crate::solution!(@common $day);
+
+        impl $crate::template::Solution for DaySolution {
+            type Parsed<'a> = &'a str;
+
+            const DAY: $crate::template::Day = DAY;
+            const PARTS: &'static [u8] = &[$( $part ),*];
+
+            fn parse(input: &str) -> &str {
+                input
+            }
+
+            $(
+                fn $func(
+                    input: &Self::Parsed<'_>,
+                    ctx: &$crate::template::Context,
+                ) -> impl $crate::template::runner::PartResult {
+                    $crate::template::Part::call(&$func, *input, ctx)
+                }
+            )*
+        }
+    };
+
+    (@impl_parse $day:expr, $parse:path, $parsed:ty, $( [$func:ident, $part:expr] )*) => {
+        $crate::solution!(@common $day);
+
+        impl $crate::template::Solution for DaySolution {
+            type Parsed<'a> = $parsed;
+
+            const DAY: $crate::template::Day = DAY;
+            const PARTS: &'static [u8] = &[$crate::template::report::PARSE_PART, $( $part ),*];
+
+            fn parse(input: &str) -> Self::Parsed<'_> {
+                $parse(input)
+            }
+
+            $(
+                fn $func(
+                    parsed: &Self::Parsed<'_>,
+                    ctx: &$crate::template::Context,
+                ) -> impl $crate::template::runner::PartResult {
+                    $crate::template::Part::call(&$func, parsed, ctx)
+                }
+            )*
+        }
+    };
+
+    (@common $day:expr) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        /// The solution of the current day, see `advent_of_code::template::Solution`.
+        pub struct DaySolution;
 
-        fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
-        }
+        /// The registry entry for the current day.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::RegistryEntry =
+            $crate::template::registry::RegistryEntry::of::<DaySolution>();
     };
 }
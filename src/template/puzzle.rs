@@ -0,0 +1,273 @@
+/// The example and expected answer proposed for one part of a puzzle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartExample {
+    /// The first multi-line code block of the part's description.
+    pub input: Option<String>,
+    /// The last emphasized code span of the part's description.
+    pub answer: Option<String>,
+}
+
+/// The examples proposed for both parts of a puzzle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PuzzleExamples {
+    pub part_1: PartExample,
+    pub part_2: Option<PartExample>,
+}
+
+impl PuzzleExamples {
+    /// Parse the markdown of a puzzle, as written to `data/puzzles` by the `download` command.
+    /// The part two section is only present once part one has been solved.
+    pub fn parse(markdown: &str) -> Self {
+        let (part_1, part_2) = match markdown.find("Part Two ---") {
+            Some(index) => (&markdown[..index], Some(&markdown[index..])),
+            None => (markdown, None),
+        };
+
+        Self {
+            part_1: parse_part(part_1),
+            part_2: part_2.map(parse_part),
+        }
+    }
+
+    /// The title of the puzzle, e.g. "Day 1: Trebuchet?!".
+    pub fn title(markdown: &str) -> Option<String> {
+        markdown.lines().find_map(|line| {
+            let start = line.find("--- Day ")? + 4;
+            let end = line.rfind(" ---").filter(|end| *end > start)?;
+            Some(line[start..end].trim().to_string())
+        })
+    }
+
+    /// The example for part two, if it differs from the one of part one.
+    pub fn distinct_part_2_input(&self) -> Option<&str> {
+        let input = self.part_2.as_ref()?.input.as_deref()?;
+        (self.part_1.input.as_deref() != Some(input)).then_some(input)
+    }
+}
+
+fn parse_part(markdown: &str) -> PartExample {
+    let mut blocks = vec![];
+    let mut prose = String::new();
+    let mut block: Option<String> = None;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            match block.take() {
+                Some(b) => blocks.push(b),
+                None => block = Some(String::new()),
+            }
+        } else if let Some(b) = block.as_mut() {
+            b.push_str(line);
+            b.push('\n');
+        } else {
+            prose.push_str(line);
+            prose.push('\n');
+        }
+    }
+
+    let input = blocks
+        .iter()
+        .find(|b| b.lines().count() > 1)
+        .or(blocks.first())
+        .cloned();
+
+    PartExample {
+        input,
+        answer: emphasized_code(&prose).pop(),
+    }
+}
+
+/// Find all spans that are both code and emphasized, e.g. "`*142*`" or "*`142`*".
+/// This is how the puzzle descriptions highlight the answers to their examples.
+fn emphasized_code(prose: &str) -> Vec<String> {
+    let is_delimiter = |c: char| c == '`' || c == '*';
+    let is_code_em = |run: &str| run.contains('`') && run.contains('*');
+
+    let mut spans = vec![];
+
+    for line in prose.lines() {
+        let mut rest = line;
+
+        while let Some(start) = rest.find(is_delimiter) {
+            let after_open = &rest[start..];
+            let open_len = after_open
+                .find(|c| !is_delimiter(c))
+                .unwrap_or(after_open.len());
+            let open = &after_open[..open_len];
+
+            let after_content = &after_open[open_len..];
+            let Some(content_len) = after_content.find(is_delimiter) else {
+                break;
+            };
+            let content = &after_content[..content_len];
+
+            let after_close = &after_content[content_len..];
+            let close_len = after_close
+                .find(|c| !is_delimiter(c))
+                .unwrap_or(after_close.len());
+            let close = &after_close[..close_len];
+
+            if is_code_em(open) && is_code_em(close) && !content.trim().is_empty() {
+                spans.push(content.trim().to_string());
+                rest = &after_close[close_len..];
+            } else {
+                // the closing run may open the next span.
+                rest = after_close;
+            }
+        }
+    }
+
+    spans
+}
+
+/// Replace the placeholder assertion of a scaffolded part test with the expected answer.
+/// If `example_part` is set, the test is also pointed to the example file with that part suffix.
+/// Returns `None` if the test can not be found or was already edited.
+pub fn fill_test_assertion(
+    source: &str,
+    part: u8,
+    answer: &str,
+    example_part: Option<u8>,
+) -> Option<String> {
+    let name = if part == 1 { "one" } else { "two" };
+    let test_start = source.find(&format!("fn test_part_{name}()"))?;
+    let test_len = source[test_start..]
+        .find("\n    }")
+        .unwrap_or(source.len() - test_start);
+    let test = &source[test_start..test_start + test_len];
+
+    let placeholder = "assert_eq!(result, None);";
+    if !test.contains(placeholder) {
+        return None;
+    }
+
+    let mut filled = test.replace(
+        placeholder,
+        &format!("assert_eq!(result, {});", expected_literal(answer)),
+    );
+
+    if let Some(example_part) = example_part {
+        filled = filled.replace(
+            "read_file(\"examples\", DAY)",
+            &format!("read_file_part(\"examples\", DAY, {example_part})"),
+        );
+    }
+
+    Some(format!(
+        "{}{filled}{}",
+        &source[..test_start],
+        &source[test_start + test_len..]
+    ))
+}
+
+/// The value a part is expected to return for an answer, as Rust source.
+pub fn expected_literal(answer: &str) -> String {
+    if answer.parse::<i128>().is_ok() {
+        format!("Some({answer})")
+    } else {
+        format!("Some({answer:?}.to_string())")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PartExample, PuzzleExamples, fill_test_assertion};
+
+    const PUZZLE: &str = r"## --- Day 1: Trebuchet?! ---
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+In this example, the values of these lines are `12` and `38`. Adding these together produces `*50*`.
+
+Consider your *entire* calibration document. What is the sum?
+
+## --- Part Two ---
+
+It looks like some of the digits are spelled out. For example:
+
+```
+two1nine
+eightwothree
+```
+
+Adding these together produces *`110`*.
+";
+
+    const MODULE: &str = r#"advent_of_code::solution!(1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+    #[test]
+    fn extracts_examples_and_answers() {
+        let examples = PuzzleExamples::parse(PUZZLE);
+        assert_eq!(
+            examples.part_1,
+            PartExample {
+                input: Some("1abc2\npqr3stu8vwx\n".into()),
+                answer: Some("50".into()),
+            }
+        );
+        assert_eq!(
+            examples.distinct_part_2_input(),
+            Some("two1nine\neightwothree\n")
+        );
+        assert_eq!(examples.part_2.unwrap().answer, Some("110".into()));
+    }
+
+    #[test]
+    fn extracts_titles() {
+        assert_eq!(
+            PuzzleExamples::title(PUZZLE),
+            Some("Day 1: Trebuchet?!".into())
+        );
+        assert_eq!(
+            PuzzleExamples::title("## \\--- Day 12: Hot Springs ---"),
+            Some("Day 12: Hot Springs".into())
+        );
+        assert_eq!(PuzzleExamples::title("no title"), None);
+    }
+
+    #[test]
+    fn handles_unsolved_part_one() {
+        let examples = PuzzleExamples::parse(PUZZLE.split("## --- Part Two").next().unwrap());
+        assert_eq!(examples.part_2, None);
+        assert_eq!(examples.part_1.answer, Some("50".into()));
+    }
+
+    #[test]
+    fn fills_test_assertions() {
+        let module = fill_test_assertion(MODULE, 2, "110", Some(2)).unwrap();
+        assert!(module.contains("assert_eq!(result, Some(110));"));
+        assert!(module.contains("read_file_part(\"examples\", DAY, 2)"));
+        assert!(module.contains("read_file(\"examples\", DAY)"));
+
+        let module = fill_test_assertion(&module, 1, "abc", None).unwrap();
+        assert!(module.contains("assert_eq!(result, Some(\"abc\".to_string()));"));
+        assert!(!module.contains("assert_eq!(result, None);"));
+
+        assert_eq!(fill_test_assertion(&module, 1, "50", None), None);
+    }
+}
@@ -2,11 +2,14 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
 use crate::template::timings::Timings;
+use crate::template::{Day, chart, memory, solution_path};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
+/// Location of the benchmark chart, relative to the readme.
+pub static CHART_PATH: &str = "./.assets/benchmarks.svg";
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
@@ -21,17 +24,13 @@ impl From<std::io::Error> for Error {
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
-}
-
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    pub(crate) pos_start: usize,
+    pub(crate) pos_end: usize,
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Locate the table between the two occurences of `marker` in the readme, including the markers.
+pub(crate) fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,25 +51,40 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+/// Build the benchmark table. Days link to the file of their solution, which is resolved by `path_of`.
+fn construct_table(
+    prefix: &str,
+    timings: Timings,
+    total_millis: f64,
+    chart_path: Option<&str>,
+    path_of: impl Fn(Day) -> String,
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    if let Some(path) = chart_path {
+        lines.push(format!("![Benchmarks]({path})"));
+        lines.push(String::new());
+    }
+
+    lines.extend([
+        "| Day | Parse | Part 1 | Part 2 | Memory |".into(),
+        "| :---: | :---: | :---: | :---: | :---: |".into(),
+    ]);
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = path_of(timing.day);
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}](./{}) | `{}` | `{}` | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
+            timing.parse.unwrap_or_else(|| "-".into()),
             timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            timing.part_2.unwrap_or_else(|| "-".into()),
+            timing
+                .peak_bytes
+                .map_or_else(|| "-".into(), memory::format_bytes)
         ));
     }
 
@@ -81,47 +95,77 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    timings: Timings,
+    total_millis: f64,
+    chart_path: Option<&str>,
+    path_of: impl Fn(Day) -> String,
+) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table("##", timings, total_millis, chart_path, path_of);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+/// Rebuild the benchmark table in the readme.
+/// With `with_chart`, the timings are also drawn to an SVG chart at [`CHART_PATH`] that is embedded above the table.
+pub fn update(timings: Timings, with_chart: bool) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+
+    let chart_path = match with_chart.then(|| chart::render(&timings)).flatten() {
+        Some(svg) => {
+            fs::create_dir_all("./.assets")?;
+            fs::write(CHART_PATH, svg)?;
+            Some(CHART_PATH)
+        }
+        None => None,
+    };
+
+    update_content(
+        &mut readme,
+        timings,
+        total_millis,
+        chart_path,
+        solution_path,
+    )?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{CHART_PATH, MARKER, update_content};
+    use crate::{day, template::Day, template::timings::Timing, template::timings::Timings};
+
+    fn module_path(day: Day) -> String {
+        format!("src/days/d{day}.rs")
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    parse: Some("5ms".into()),
+                    peak_bytes: Some(1536),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -131,28 +175,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None, module_path).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None, module_path).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None, module_path).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None, module_path).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None, module_path).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,18 +204,18 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None, module_path).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 | Memory |",
+            "| :---: | :---: | :---: | :---: | :---: |",
+            "| [Day 1](./src/days/d01.rs) | `-` | `10ms` | `20ms` | `-` |",
+            "| [Day 2](./src/days/d02.rs) | `5ms` | `30ms` | `40ms` | `1.5 KiB` |",
+            "| [Day 4](./src/days/d04.rs) | `-` | `40ms` | `50ms` | `-` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +224,29 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn embeds_chart() {
+        let mut s = format!("foo\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            Some(CHART_PATH),
+            module_path,
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            Some(CHART_PATH),
+            module_path,
+        )
+        .unwrap();
+        assert!(s.contains(
+            "## Benchmarks\n\n![Benchmarks](./.assets/benchmarks.svg)\n\n| Day | Parse |"
+        ));
+        assert_eq!(s.matches("![Benchmarks]").count(), 1);
+    }
 }
@@ -0,0 +1,257 @@
+/// Module that updates the readme with the ⭐️ progress of the configured year.
+/// Maintains the same table as the `advent-readme-stars` action, but from local state: a part counts as solved
+/// if it has a confirmed answer in `data/answers.json`, a correct submission in `data/submissions.json` or stored
+/// benchmarks of its whole day in `data/timings.json`. Stars already shown in the table are kept.
+use std::fs;
+
+use crate::template::answers::Answers;
+use crate::template::readme_benchmarks::{Error, locate_table};
+use crate::template::submissions::{Submissions, Verdict};
+use crate::template::timings::Timings;
+use crate::template::{Day, all_days};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// The solved parts of a single day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stars {
+    pub day: Day,
+    pub part_1: bool,
+    pub part_2: bool,
+}
+
+impl Stars {
+    pub fn count(&self) -> usize {
+        usize::from(self.part_1) + usize::from(self.part_2)
+    }
+}
+
+/// Collect the solved parts of every day of the event.
+/// A part that is starred in `shown`, the table currently in the readme, stays solved even without a local record.
+pub fn collect(
+    answers: &Answers,
+    submissions: &Submissions,
+    timings: &Timings,
+    shown: &[Stars],
+) -> Vec<Stars> {
+    let is_solved = |day: Day, part: u8| {
+        let is_shown = shown
+            .iter()
+            .any(|s| s.day == day && if part == 1 { s.part_1 } else { s.part_2 });
+
+        is_shown
+            || answers.get(day, part).is_some()
+            || timings.is_day_complete(day)
+            || submissions
+                .data
+                .iter()
+                .any(|s| s.day == day && s.part == part && s.verdict == Verdict::Correct)
+    };
+
+    all_days()
+        .map(|day| Stars {
+            day,
+            part_1: is_solved(day, 1),
+            part_2: is_solved(day, 2),
+        })
+        .collect()
+}
+
+/// Read the stars shown in a table built by [`construct_table`] or the `advent-readme-stars` action.
+fn parse_table(table: &str) -> Vec<Stars> {
+    table
+        .lines()
+        .filter_map(|line| {
+            let cells: Vec<&str> = line.split('|').map(str::trim).collect();
+            let ["", day, part_1, part_2, ..] = cells[..] else {
+                return None;
+            };
+
+            let day = day.strip_prefix("[Day ")?.split(']').next()?.parse().ok()?;
+
+            Some(Stars {
+                day,
+                part_1: part_1.contains('⭐'),
+                part_2: part_2.contains('⭐'),
+            })
+        })
+        .collect()
+}
+
+fn construct_table(year: u16, stars: &[Stars]) -> String {
+    let star = |solved: bool| if solved { "⭐" } else { " " };
+
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        format!("## {year} Results"),
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    for day in stars {
+        let day_number = day.day.into_inner();
+        lines.push(format!(
+            "| [Day {day_number}](https://adventofcode.com/{year}/day/{day_number}) | {} | {} |",
+            star(day.part_1),
+            star(day.part_2)
+        ));
+    }
+
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, year: u16, stars: &[Stars]) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table(year, stars);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+/// Rebuild the stars table in the readme. Returns the number of stars.
+pub fn update(year: u16) -> Result<usize, Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    let answers = Answers::read_from_file().map_err(Error::Parser)?;
+    let submissions = Submissions::read_from_file().map_err(Error::Parser)?;
+    let positions = locate_table(&readme, MARKER)?;
+    let shown = parse_table(&readme[positions.pos_start..positions.pos_end]);
+    let stars = collect(&answers, &submissions, &Timings::read_from_file(), &shown);
+    update_content(&mut readme, year, &stars)?;
+    fs::write(path, &readme)?;
+    Ok(stars.iter().map(Stars::count).sum())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{MARKER, Stars, collect, construct_table, parse_table, update_content};
+    use crate::{
+        day,
+        template::{
+            LAST_DAY,
+            answers::{Answer, Answers},
+            submissions::{Submission, Submissions, Verdict},
+            timings::{Timing, Timings},
+        },
+    };
+
+    fn get_mock_stars() -> Vec<Stars> {
+        vec![
+            Stars {
+                day: day!(1),
+                part_1: true,
+                part_2: true,
+            },
+            Stars {
+                day: day!(2),
+                part_1: true,
+                part_2: false,
+            },
+            Stars {
+                day: day!(3),
+                part_1: false,
+                part_2: false,
+            },
+        ]
+    }
+
+    #[test]
+    fn collects_stars_from_answers_and_submissions() {
+        let answers = Answers {
+            data: vec![Answer {
+                day: day!(1),
+                part_1: Some("42".into()),
+                part_2: Some("7".into()),
+            }],
+        };
+
+        let submissions = Submissions {
+            data: vec![
+                Submission {
+                    day: day!(2),
+                    part: 1,
+                    answer: "10".into(),
+                    verdict: Verdict::Correct,
+                },
+                Submission {
+                    day: day!(2),
+                    part: 2,
+                    answer: "11".into(),
+                    verdict: Verdict::TooHigh,
+                },
+            ],
+        };
+
+        let stars = collect(&answers, &submissions, &Timings::default(), &[]);
+        assert_eq!(stars.len(), usize::from(LAST_DAY));
+        assert_eq!(&stars[..3], &get_mock_stars()[..]);
+        assert_eq!(stars.iter().map(Stars::count).sum::<usize>(), 3);
+    }
+
+    #[test]
+    fn collects_stars_from_timings_and_keeps_shown_stars() {
+        let timings = Timings {
+            data: vec![Timing {
+                part_1: Some("1ms".into()),
+                part_2: Some("2ms".into()),
+                ..Timing::new(day!(3))
+            }],
+        };
+
+        let stars = collect(
+            &Answers::default(),
+            &Submissions::default(),
+            &timings,
+            &get_mock_stars(),
+        );
+        assert_eq!(stars[0].count(), 2);
+        assert_eq!(stars[1].count(), 1);
+        assert_eq!(stars[2].count(), 2);
+        assert_eq!(stars.iter().map(Stars::count).sum::<usize>(), 5);
+    }
+
+    #[test]
+    fn parses_shown_stars() {
+        let table = construct_table(2025, &get_mock_stars());
+        assert_eq!(parse_table(&table), get_mock_stars());
+        assert_eq!(
+            parse_table("| [Day 12](https://adventofcode.com/2025/day/12) | ⭐️ |  |"),
+            vec![Stars {
+                day: day!(12),
+                part_1: true,
+                part_2: false,
+            }]
+        );
+        assert!(parse_table("| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |").is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, 2025, &get_mock_stars()).unwrap();
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{}\n## 2025 Results\n{}\nbar", MARKER, MARKER);
+        update_content(&mut s, 2025, &get_mock_stars()).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2025 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2025/day/2) | ⭐ |   |",
+            "| [Day 3](https://adventofcode.com/2025/day/3) |   |   |",
+            "<!--- advent_readme_stars table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
@@ -0,0 +1,32 @@
+/// In-process registry of solutions.
+/// Every day registers itself via the `solution!` macro. The main binary compiles all days into a single
+/// list (see `build.rs`), which lets `all` and `time` run solutions without spawning cargo once per day.
+use crate::template::{Day, report::PartReport, runner, solution};
+
+/// A solution as registered by the `solution!` macro.
+#[derive(Clone, Copy)]
+pub struct RegistryEntry {
+    pub day: Day,
+    /// The parts the solution reports on, in the order they run, including [`PARSE_PART`](crate::template::report::PARSE_PART) if it has a parse function.
+    pub parts: &'static [u8],
+    /// Runs every part of the solution against an input, optionally benching it.
+    pub run: fn(&str, bool) -> Vec<PartReport>,
+}
+
+impl RegistryEntry {
+    /// The registry entry of a solution, running it via [`runner::report_solution`].
+    #[must_use]
+    pub const fn of<S: solution::Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            parts: S::PARTS,
+            run: runner::report_solution::<S>,
+        }
+    }
+}
+
+/// Looks up the solution for a day.
+#[must_use]
+pub fn find(solutions: &[RegistryEntry], day: Day) -> Option<&RegistryEntry> {
+    solutions.iter().find(|s| s.day == day)
+}
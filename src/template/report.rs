@@ -0,0 +1,244 @@
+/// Machine-readable protocol between solution binaries and `run_multi`.
+/// When the `AOC_REPORT_FILE` environment variable is set, `run_part` appends one JSON line per part to that file,
+/// so the parent process never has to scrape the human-readable output.
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, runner::BenchStats};
+
+pub const REPORT_FILE_ENV: &str = "AOC_REPORT_FILE";
+
+/// Part number of the report that times the shared parse phase of a solution.
+pub const PARSE_PART: u8 = 0;
+
+/// Represents the outcome of running a single part of a solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartReport {
+    pub day: Day,
+    /// The part number, or [`PARSE_PART`] for the parse phase.
+    pub part: u8,
+    pub answer: Option<String>,
+    /// The error returned by the part, or its panic message.
+    pub error: Option<String>,
+    /// Peak heap usage of the solution up to the end of this part, if memory is tracked.
+    pub peak_bytes: Option<u64>,
+    pub stats: BenchStats,
+}
+
+impl PartReport {
+    /// Append the report to the file named by `AOC_REPORT_FILE`. Does nothing if the variable is not set.
+    pub fn write_to_env_file(&self) -> Result<(), io::Error> {
+        let Ok(path) = env::var(REPORT_FILE_ENV) else {
+            return Ok(());
+        };
+
+        let line = JsonValue::from(self)
+            .stringify()
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+
+        writeln!(file, "{line}")
+    }
+
+    /// Read all reports from a report file. If not present, returns no reports.
+    pub fn read_from_file(path: &Path) -> Result<Vec<Self>, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse_lines(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Parse a set of JSON lines into reports, skipping empty lines.
+    pub fn parse_lines(contents: &str) -> Result<Vec<Self>, String> {
+        contents
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| {
+                let json = JsonValue::from_str(l).or(Err("report is not valid JSON."))?;
+                PartReport::try_from(&json)
+            })
+            .collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartReport> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        let nanos = |d: Duration| JsonValue::Number(d.as_nanos() as f64);
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        if let Some(error) = &value.error {
+            map.insert("error".into(), JsonValue::String(error.clone()));
+        }
+        if let Some(peak_bytes) = value.peak_bytes {
+            map.insert("peak_bytes".into(), JsonValue::Number(peak_bytes as f64));
+        }
+        map.insert("mean_nanos".into(), nanos(value.stats.mean));
+        map.insert("median_nanos".into(), nanos(value.stats.median));
+        map.insert("std_dev_nanos".into(), nanos(value.stats.std_dev));
+        map.insert("min_nanos".into(), nanos(value.stats.min));
+        map.insert("max_nanos".into(), nanos(value.stats.max));
+        map.insert("p95_nanos".into(), nanos(value.stats.p95));
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.samples as f64),
+        );
+        map.insert(
+            "outliers".into(),
+            JsonValue::Number(value.stats.outliers as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected report.{key} to be a number."))
+        };
+
+        let nanos = |key: &str| number(key).map(|n| Duration::from_nanos(n as u64));
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected report.day to be a Day struct.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.answer to be null or string.")?;
+
+        let error = json.get("error").and_then(|v| v.get::<String>()).cloned();
+        let peak_bytes = json
+            .get("peak_bytes")
+            .and_then(|v| v.get::<f64>())
+            .map(|bytes| *bytes as u64);
+
+        Ok(PartReport {
+            day,
+            part: number("part")? as u8,
+            answer: answer.cloned(),
+            error,
+            peak_bytes,
+            stats: BenchStats {
+                mean: nanos("mean_nanos")?,
+                median: nanos("median_nanos")?,
+                std_dev: nanos("std_dev_nanos")?,
+                min: nanos("min_nanos")?,
+                max: nanos("max_nanos")?,
+                p95: nanos("p95_nanos")?,
+                samples: number("samples")? as u128,
+                outliers: number("outliers")? as usize,
+            },
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::PartReport;
+    use crate::{day, template::runner::BenchStats};
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    fn get_mock_report(answer: Option<&str>) -> PartReport {
+        PartReport {
+            day: day!(8),
+            part: 2,
+            answer: answer.map(Into::into),
+            error: None,
+            peak_bytes: None,
+            stats: BenchStats::from_samples(&[
+                Duration::from_nanos(100),
+                Duration::from_nanos(200),
+                Duration::from_nanos(300),
+            ]),
+        }
+    }
+
+    #[test]
+    fn roundtrips_reports() {
+        let report = get_mock_report(Some("25272"));
+        let line = JsonValue::from(&report).stringify().unwrap();
+        let parsed = PartReport::parse_lines(&line).unwrap();
+        assert_eq!(parsed, vec![report]);
+    }
+
+    #[test]
+    fn roundtrips_answers_with_output_patterns() {
+        let report = get_mock_report(Some("@ @ @ ( ) ms (2s @ 5 samples)\nPart 1: 3"));
+        let line = JsonValue::from(&report).stringify().unwrap();
+        let parsed = PartReport::parse_lines(&line).unwrap();
+        assert_eq!(parsed, vec![report]);
+    }
+
+    #[test]
+    fn roundtrips_missing_answers() {
+        let report = get_mock_report(None);
+        let line = JsonValue::from(&report).stringify().unwrap();
+        let parsed = PartReport::parse_lines(&format!("{line}\n\n")).unwrap();
+        assert_eq!(parsed, vec![report]);
+    }
+
+    #[test]
+    fn roundtrips_errors() {
+        let mut report = get_mock_report(None);
+        report.error = Some("panicked at src/bin/08.rs:1:1: oops".into());
+        let line = JsonValue::from(&report).stringify().unwrap();
+        let parsed = PartReport::parse_lines(&line).unwrap();
+        assert_eq!(parsed, vec![report]);
+    }
+
+    #[test]
+    fn roundtrips_peak_memory() {
+        let mut report = get_mock_report(Some("25272"));
+        report.peak_bytes = Some(3 << 20);
+        let line = JsonValue::from(&report).stringify().unwrap();
+        let parsed = PartReport::parse_lines(&line).unwrap();
+        assert_eq!(parsed, vec![report]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_reports() {
+        PartReport::parse_lines(r#"{ "day": "08", "part": 1 }"#).unwrap();
+    }
+}
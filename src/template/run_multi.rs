@@ -1,16 +1,67 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+    io,
+    process::ExitStatus,
+};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day,
+    answers::{Answers, Verification},
+    memory,
+    registry::{self, RegistryEntry},
+    report::PartReport,
+    threads,
+    timeouts::Timeouts,
+    try_read_file,
+};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Outcome of running a set of days.
+pub struct RunSummary {
+    /// Benchmark times, if the days were timed.
+    pub timings: Option<Timings>,
+    /// Number of parts whose output did not match their confirmed answer.
+    pub mismatches: usize,
+    /// Number of parts that returned an error or panicked, and of solutions that could not be run.
+    /// Also counts a confirmed answers file that could not be read.
+    pub failures: usize,
+}
+
+/// Run a set of days, in-process if they are part of `solutions` and via their solution binary otherwise.
+/// The output of every part is verified against the confirmed answers in `data/answers.json`.
+///
+/// Days with a time limit in `timeouts` run in their solution binary, which is killed if a part takes longer than
+/// its limit. In-process runs cannot be interrupted.
+///
+/// In-process runs use the profile of the current binary. If it is a debug build and `is_release` is set, every day
+/// runs in its optimized solution binary instead.
+///
+/// With `threads`, solutions run on a thread pool of that size instead of the default pool.
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    timeouts: &Timeouts,
+    threads: Option<usize>,
+    solutions: &[RegistryEntry],
+) -> RunSummary {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut mismatches = 0;
+    let mut failures = 0;
+
+    let answers = Answers::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to read confirmed answers, not verifying any: {e}");
+        failures += 1;
+        Answers::default()
+    });
 
     let mut need_space = false;
+    let can_run_in_process = !(is_release && cfg!(debug_assertions));
 
     // NOTE: use non-duplicate, sorted day values.
     all_days()
@@ -24,17 +75,63 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let solution = registry::find(solutions, day);
+
+            let run = match solution {
+                Some(solution) if can_run_in_process && !timeouts.applies_to(day) => {
+                    Ok(SolutionRun {
+                        reports: run_in_process(solution, is_timed, threads),
+                        timed_out: None,
+                    })
+                }
+                _ => {
+                    // NOTE: solutions that are not registered are assumed to have both parts and no parse function.
+                    let parts = solution.map_or(&[1, 2][..], |s| s.parts);
+                    child_commands::run_solution(
+                        day, parts, is_timed, is_release, timeouts, threads,
+                    )
+                }
+            };
 
-            if output.is_empty() {
+            let SolutionRun { reports, timed_out } = run.unwrap_or_else(|e| {
+                eprintln!("✘ Failed to run solution: {e}");
+                failures += 1;
+                SolutionRun::default()
+            });
+
+            failures += reports.iter().filter(|r| r.error.is_some()).count();
+            failures += usize::from(timed_out.is_some());
+
+            if reports.is_empty() && timed_out.is_none() {
                 println!("Not solved.");
             } else {
-                let val = child_commands::parse_exec_time(&output, day);
+                mismatches += verify_reports(&answers, &reports);
+                let mut val = child_commands::timing_from_reports(&reports, day);
+                // NOTE: child solutions run with the same default pool size as the current process.
+                val.threads = Some(threads::effective(threads));
+                if let Some(part) = timed_out
+                    && let Some(limit) = timeouts.limit(day, part)
+                {
+                    val.mark_timed_out(part, limit);
+                }
+                if let Some(peak_bytes) = val.peak_bytes {
+                    println!("Peak memory: {}", memory::format_bytes(peak_bytes));
+                }
                 timings.push(val);
             }
         });
 
-    if is_timed {
+    if mismatches > 0 {
+        println!(
+            "\n{ANSI_BOLD}✘ {mismatches} part(s) did not match their confirmed answer.{ANSI_RESET}"
+        );
+    }
+
+    if failures > 0 {
+        println!("\n{ANSI_BOLD}✘ {failures} part(s) failed.{ANSI_RESET}");
+    }
+
+    let timings = if is_timed {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
@@ -43,14 +140,83 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
         Some(timings)
     } else {
         None
+    };
+
+    RunSummary {
+        timings,
+        mismatches,
+        failures,
     }
 }
 
-#[allow(dead_code)]
+/// Print the verification status of each part that has a confirmed answer.
+/// Returns the number of mismatches.
+fn verify_reports(answers: &Answers, reports: &[PartReport]) -> usize {
+    let mut mismatches = 0;
+
+    // NOTE: parts that failed are counted as failures instead.
+    for report in reports.iter().filter(|r| r.error.is_none()) {
+        let answer = report.answer.as_deref().unwrap_or_default();
+
+        match answers.verify(report.day, report.part, answer) {
+            Verification::Unknown => {}
+            Verification::Match => {
+                println!("✔ Part {} matches the confirmed answer.", report.part);
+            }
+            Verification::Mismatch { expected } => {
+                println!(
+                    "✘ Part {} does not match the confirmed answer {ANSI_BOLD}{expected}{ANSI_RESET}.",
+                    report.part
+                );
+                mismatches += 1;
+            }
+        }
+    }
+
+    mismatches
+}
+
+/// Run a registered solution against its input in the current process.
+fn run_in_process(
+    solution: &RegistryEntry,
+    is_timed: bool,
+    threads: Option<usize>,
+) -> Vec<PartReport> {
+    match try_read_file("inputs", solution.day) {
+        Ok(input) => threads::install(threads, || (solution.run)(&input, is_timed)),
+        Err(e) => {
+            eprintln!("Error: {e}");
+            vec![]
+        }
+    }
+}
+
+/// The reports of a solution that ran, and the part it was killed in if it exceeded the timeout.
+#[derive(Debug, Default)]
+pub struct SolutionRun {
+    pub reports: Vec<PartReport>,
+    pub timed_out: Option<u8>,
+}
+
 #[derive(Debug)]
 pub enum Error {
-    BrokenPipe,
     IO(io::Error),
+    Report(String),
+    /// The solution binary did not exit successfully, or failed to compile.
+    Exit(ExitStatus),
+    /// The solution binary was built, but cargo did not report its path.
+    MissingExecutable,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::IO(e) => write!(f, "{e}"),
+            Error::Report(e) => write!(f, "invalid report: {e}"),
+            Error::Exit(status) => write!(f, "solution exited unsuccessfully ({status})"),
+            Error::MissingExecutable => write!(f, "could not find the solution executable"),
+        }
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -65,128 +231,234 @@ pub fn get_path_for_bin(day: Day) -> String {
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting their reports.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{Error, SolutionRun, get_path_for_bin};
+    use crate::template::{
+        Day, memory,
+        report::{PARSE_PART, PartReport, REPORT_FILE_ENV},
+        timeouts::Timeouts,
+        timings::PartStats,
+        try_read_file,
+    };
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        collections::HashMap,
+        env, fs,
+        path::{Path, PathBuf},
+        process::{self, Command, Stdio},
+        str::FromStr,
         thread,
+        time::{Duration, Instant},
     };
-
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    use tinyjson::JsonValue;
+
+    /// Interval at which a running solution is checked for progress when a timeout is set.
+    const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+    /// Run the solution bin for a given day and collect the reports it wrote for each part.
+    /// `parts` are the parts the solution reports on, in order, and determine which part timed out.
+    ///
+    /// The solution is killed if it does not finish a part within the limit of the part in `timeouts`.
+    pub fn run_solution(
+        day: Day,
+        parts: &[u8],
+        is_timed: bool,
+        is_release: bool,
+        timeouts: &Timeouts,
+        threads: Option<usize>,
+    ) -> Result<SolutionRun, Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+            return Ok(SolutionRun::default());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
-
-        if is_release {
-            args.push("--release");
+        // a missing input is not a failure of the solution, report it like an in-process run instead.
+        if let Err(e) = try_read_file("inputs", day) {
+            eprintln!("Error: {e}");
+            return Ok(SolutionRun::default());
         }
 
+        // NOTE: the bin is run directly instead of via `cargo run`, so killing it does not orphan the solution.
+        let executable = build_solution(day, is_release)?;
+
+        let mut args = vec![];
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            args.push("--time".to_string());
+        }
+
+        if memory::is_enabled() {
+            args.push("--memory".to_string());
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        if let Some(threads) = threads {
+            args.extend(["--threads".to_string(), threads.to_string()]);
+        }
+
+        // the child forwards its human-readable output to the terminal and writes
+        // one machine-readable report per part into a temporary file.
+        let report_path = get_report_path(day);
+        let _ = fs::remove_file(&report_path);
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(executable)
             .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
+            .env(REPORT_FILE_ENV, &report_path)
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
             .spawn()?;
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        let status = if timeouts.applies_to(day) {
+            let mut reported = 0;
+            let mut part_started = Instant::now();
+
+            loop {
+                if let Some(status) = cmd.try_wait()? {
+                    break Some(status);
+                }
+
+                // every report marks the end of a part, so the next part starts with its own time limit.
+                let count = count_reports(&report_path);
+                if count > reported {
+                    reported = count;
+                    part_started = Instant::now();
+                }
+
+                let limit = parts
+                    .get(reported)
+                    .and_then(|part| timeouts.limit(day, *part));
 
-        let mut output = vec![];
+                if limit.is_some_and(|limit| part_started.elapsed() > limit) {
+                    cmd.kill()?;
+                    cmd.wait()?;
+                    break None;
+                }
+
+                thread::sleep(POLL_INTERVAL);
+            }
+        } else {
+            Some(cmd.wait()?)
+        };
+
+        let reports = PartReport::read_from_file(&report_path).map_err(Error::Report);
+        let _ = fs::remove_file(&report_path);
+        let reports = reports?;
+
+        let Some(status) = status else {
+            let timed_out = parts
+                .iter()
+                .copied()
+                .find(|part| !reports.iter().any(|r| r.part == *part))
+                .unwrap_or(PARSE_PART);
+
+            let label = if timed_out == PARSE_PART {
+                "Parse".into()
+            } else {
+                format!("Part {timed_out}")
+            };
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+            println!(
+                "\r{label}: ✖ timed out after {:.1?}, killed solution.",
+                timeouts.limit(day, timed_out).unwrap_or_default()
+            );
+
+            return Ok(SolutionRun {
+                reports,
+                timed_out: Some(timed_out),
             });
-        });
+        };
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        if !status.success() {
+            return Err(Error::Exit(status));
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
-
-        Ok(output)
+        Ok(SolutionRun {
+            reports,
+            timed_out: None,
+        })
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    /// Build the solution bin for a given day and return the path of its executable.
+    fn build_solution(day: Day, is_release: bool) -> Result<PathBuf, Error> {
+        let day_padded = day.to_string();
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--message-format=json-render-diagnostics",
+            "--bin",
+            &day_padded,
+        ];
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        if is_release {
+            args.push("--release");
+        }
+
+        let output = Command::new("cargo")
+            .args(&args)
+            .stderr(Stdio::inherit())
+            .output()?;
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        if !output.status.success() {
+            return Err(Error::Exit(output.status));
+        }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| JsonValue::from_str(line).ok())
+            .find_map(|message| {
+                message
+                    .get::<HashMap<String, JsonValue>>()?
+                    .get("executable")?
+                    .get::<String>()
+                    .map(PathBuf::from)
             })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
-
-                timings.total_nanos += nanos;
-            });
+            .ok_or(Error::MissingExecutable)
+    }
 
-        timings
+    /// Number of reports a running solution has written so far.
+    fn count_reports(path: &Path) -> usize {
+        fs::read_to_string(path).map_or(0, |contents| contents.matches('\n').count())
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    fn get_report_path(day: Day) -> PathBuf {
+        env::temp_dir().join(format!("aoc-report-{}-{day}.jsonl", process::id()))
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    #[allow(clippy::cast_precision_loss)]
+    pub fn timing_from_reports(reports: &[PartReport], day: Day) -> super::Timing {
+        let mut timings = super::Timing::new(day);
+
+        reports
+            .iter()
+            .filter(|r| r.day == day && r.error.is_none())
+            .filter(|r| r.answer.is_some() || r.part == PARSE_PART)
+            .for_each(|r| {
+                let timing_str = format!("{:.1?}", r.stats.mean);
+                let stats = Some(PartStats::from(&r.stats));
+
+                match r.part {
+                    PARSE_PART => {
+                        timings.parse = Some(timing_str);
+                        timings.parse_stats = stats;
+                    }
+                    1 => {
+                        timings.part_1 = Some(timing_str);
+                        timings.part_1_stats = stats;
+                    }
+                    2 => {
+                        timings.part_2 = Some(timing_str);
+                        timings.part_2_stats = stats;
+                    }
+                    _ => return,
+                }
+
+                timings.total_nanos += r.stats.mean.as_nanos() as f64;
+            });
+
+        // every report holds the peak of the solution so far, including failed parts.
+        timings.peak_bytes = reports.iter().filter_map(|r| r.peak_bytes).max();
+
+        timings
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,47 +477,115 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::timing_from_reports;
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                Day,
+                report::{PARSE_PART, PartReport},
+                runner::BenchStats,
+            },
+        };
+
+        fn report(day: Day, part: u8, answer: Option<&str>, nanos: &[u64]) -> PartReport {
+            let samples: Vec<Duration> = nanos.iter().copied().map(Duration::from_nanos).collect();
+            PartReport {
+                day,
+                part,
+                answer: answer.map(Into::into),
+                error: None,
+                peak_bytes: None,
+                stats: BenchStats::from_samples(&samples),
+            }
+        }
 
-        use crate::day;
+        #[test]
+        fn collects_execution_times() {
+            let res = timing_from_reports(
+                &[
+                    report(day!(1), 1, Some("0"), &[74, 74, 74]),
+                    report(day!(1), 2, Some("10"), &[74_130_000]),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn collects_parse_times() {
+            let res = timing_from_reports(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    report(day!(1), PARSE_PART, None, &[1000]),
+                    report(day!(1), 1, Some("0"), &[74]),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 1074_f64);
+            assert_eq!(res.parse.unwrap(), "1.0µs");
+            assert!(res.parse_stats.is_some());
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+        }
+
+        #[test]
+        fn collects_execution_stats() {
+            let res = timing_from_reports(
+                &[report(day!(1), 1, Some("42"), &[1000, 2000, 3000])],
+                day!(1),
+            );
+            let stats = res.part_1_stats.unwrap();
+            assert_approx_eq!(stats.median_nanos, 2000_f64);
+            assert_approx_eq!(stats.std_dev_nanos, 816_f64);
+            assert_approx_eq!(stats.min_nanos, 1000_f64);
+            assert_approx_eq!(stats.max_nanos, 3000_f64);
+            assert_eq!(stats.samples, 3);
+            assert_eq!(res.part_2_stats.is_none(), true);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn collects_with_patterns_in_answers() {
+            let res = timing_from_reports(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    report(
+                        day!(1),
+                        1,
+                        Some("@ @ @ ( ) ms (2s @ 5 samples)"),
+                        &[2_000_000_000],
+                    ),
+                    report(day!(1), 2, Some("10s"), &[100_000_000]),
                 ],
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2.unwrap(), "100.0ms");
+        }
+
+        #[test]
+        fn collects_peak_memory() {
+            let mut parse = report(day!(1), PARSE_PART, None, &[10]);
+            parse.peak_bytes = Some(1024);
+            let mut part_1 = report(day!(1), 1, Some("1"), &[10]);
+            part_1.peak_bytes = Some(4096);
+            let mut part_2 = report(day!(1), 2, Some("2"), &[10]);
+            part_2.peak_bytes = Some(2048);
+
+            let res = timing_from_reports(&[parse, part_1, part_2], day!(1));
+            assert_eq!(res.peak_bytes, Some(4096));
+
+            let res = timing_from_reports(&[report(day!(1), 1, Some("1"), &[10])], day!(1));
+            assert_eq!(res.peak_bytes, None);
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
+        fn collects_missing_parts() {
+            let res = timing_from_reports(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    report(day!(1), 1, None, &[10]),
+                    report(day!(1), 2, None, &[10]),
                 ],
                 day!(1),
             );
@@ -1,56 +1,330 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answers::Answers;
+use crate::template::report::{PARSE_PART, PartReport};
+use crate::template::submissions::{Submissions, Verdict};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{Context, InputSource, Solution, memory, read_input_with_context, threads};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Run a solution binary: read the input selected on the command-line, then run, report and submit every part.
+pub fn run_solution<S: Solution>() {
+    threads::configure_global();
+    let (input, ctx) = read_input_with_context(S::DAY);
+    track_memory();
+
+    let Some(parsed) = parse_input::<S>(&input) else {
+        return;
+    };
+
+    for &part in S::PARTS {
+        // NOTE: with `--example`, a part with an example file of its own, e.g. `01-2.txt`, runs against it.
+        match ctx.source.read_part(S::DAY, part) {
+            None => run_solution_part::<S>(&parsed, &ctx, part),
+            Some(Ok(part_input)) => {
+                if let Some(part_parsed) = parse_input::<S>(&part_input) {
+                    run_solution_part::<S>(&part_parsed, &ctx, part);
+                }
+            }
+            Some(Err(e)) => {
+                eprintln!("Error: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Parse the input of a solution binary, timing the parse function if the solution has one.
+/// Returns `None` if the parse function panicked.
+fn parse_input<S: Solution>(input: &str) -> Option<S::Parsed<'_>> {
+    if S::has_parse() {
+        run_parse(S::parse, input, S::DAY)
+    } else {
+        Some(S::parse(input))
+    }
+}
+
+fn run_solution_part<S: Solution>(parsed: &S::Parsed<'_>, ctx: &Context, part: u8) {
+    match part {
+        1 => run_part(|parsed| S::part_one(parsed, ctx), parsed, S::DAY, 1),
+        2 => run_part(|parsed| S::part_two(parsed, ctx), parsed, S::DAY, 2),
+        _ => {}
+    }
+}
+
+/// Run every part of a solution against its puzzle input in the current process and return a report per part.
+/// Used by the in-process solution registry.
+pub fn report_solution<S: Solution>(input: &str, is_timed: bool) -> Vec<PartReport> {
+    let ctx = Context::new(InputSource::Puzzle);
+    memory::reset();
+
+    let mut reports = vec![];
+
+    let parsed = if S::has_parse() {
+        let (parsed, report) = report_parse(S::parse, input, S::DAY, is_timed);
+        reports.push(report);
+        let Some(parsed) = parsed else {
+            return reports;
+        };
+        parsed
+    } else {
+        S::parse(input)
+    };
+
+    for part in S::PARTS {
+        let report = match part {
+            1 => report_part(
+                |parsed| S::part_one(parsed, &ctx),
+                &parsed,
+                S::DAY,
+                1,
+                is_timed,
+            ),
+            2 => report_part(
+                |parsed| S::part_two(parsed, &ctx),
+                &parsed,
+                S::DAY,
+                2,
+                is_timed,
+            ),
+            _ => continue,
+        };
+        reports.push(report);
+    }
+
+    reports
+}
+
+pub fn run_part<I: Copy, R: PartResult>(func: impl Fn(I) -> R, input: I, day: Day, part: u8) {
+    let is_timed = env::args().any(|x| x == "--time");
+    let report = report_part(func, input, day, part, is_timed);
+
+    if let Err(e) = report.write_to_env_file() {
+        eprintln!("Failed to write report for part {part}: {e}");
+    }
+
+    if let Some(answer) = report.answer
+        && let Some(Verdict::Correct) = submit_result(&answer, day, part)
+    {
+        store_answer(day, part, &answer);
+    }
+}
+
+/// Start measuring the peak heap usage of the solution, if `cargo time --memory` passed `--memory` on to it.
+pub fn track_memory() {
+    if env::args().any(|x| x == "--memory") {
+        memory::enable();
+    }
+    memory::reset();
+}
+
+/// Run the parse function of a solution, print its duration and return its output, which is shared by both parts.
+/// Returns `None` if the parse function panicked.
+pub fn run_parse<I: Copy, P>(func: impl Fn(I) -> P, input: I, day: Day) -> Option<P> {
+    let is_timed = env::args().any(|x| x == "--time");
+    let (parsed, report) = report_parse(func, input, day, is_timed);
+
+    if let Err(e) = report.write_to_env_file() {
+        eprintln!("Failed to write report for parsing: {e}");
+    }
+
+    parsed
+}
+
+/// Run the parse function of a solution, print its duration and return its output along with a report.
+/// Used when solutions are run in-process, see [`report_solution`].
+pub fn report_parse<I: Copy, P>(
+    func: impl Fn(I) -> P,
+    input: I,
+    day: Day,
+    is_timed: bool,
+) -> (Option<P>, PartReport) {
+    let (parsed, stats) = run_timed(func, input, is_timed, |_| {
+        print!("Parse:");
+        true
+    });
+
+    print!("\r");
+    match &parsed {
+        Ok(_) => println!("Parse:{}", format_duration(&stats)),
+        Err(e) => println!("Parse: ✖ {e}"),
+    }
+
+    let report = PartReport {
+        day,
+        part: PARSE_PART,
+        answer: None,
+        error: parsed.as_ref().err().cloned(),
+        peak_bytes: memory::peak_bytes(),
+        stats,
+    };
+
+    (parsed.ok(), report)
+}
+
+/// Record a correct submission in the known-answer store.
+/// Nothing is stored if the store can not be read, as writing it would lose the answers in it.
+fn store_answer(day: Day, part: u8, answer: &str) {
+    let mut answers = match Answers::read_from_file() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read confirmed answers, not storing the answer: {e}");
+            return;
+        }
+    };
+    answers.insert(day, part, answer);
+
+    match answers.store_file() {
+        Ok(()) => println!("Stored confirmed answer for part {part}."),
+        Err(e) => eprintln!("Failed to store confirmed answer: {e}"),
+    }
+}
+
+/// The return type of a part function.
+/// Parts return `Option<T>`, where `None` marks the part as not solved, or `Result<T, E>` to explain a failure.
+pub trait PartResult {
+    /// The answer, `Ok(None)` if the part is not solved, or the error message if it failed.
+    fn to_answer(&self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> PartResult for Option<T> {
+    fn to_answer(&self) -> Result<Option<String>, String> {
+        Ok(self.as_ref().map(ToString::to_string))
+    }
+}
+
+impl<T: Display, E: Display> PartResult for Result<T, E> {
+    fn to_answer(&self) -> Result<Option<String>, String> {
+        match self {
+            Ok(answer) => Ok(Some(answer.to_string())),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// Run a solution part, print its result and return a report for it.
+/// Used when solutions are run in-process, see [`report_solution`].
+/// A panic in the part is caught and reported as an error.
+pub fn report_part<I: Copy, R: PartResult>(
+    func: impl Fn(I) -> R,
+    input: I,
+    day: Day,
+    part: u8,
+    is_timed: bool,
+) -> PartReport {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, stats) = run_timed(func, input, is_timed, |result| {
+        let answer = result.to_answer();
+        print_result(&answer, &part_str, "");
+        answer.is_ok()
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let answer = result.and_then(|result| result.to_answer());
+    print_result(&answer, &part_str, &format_duration(&stats));
+
+    let (answer, error) = match answer {
+        Ok(answer) => (answer, None),
+        Err(e) => (None, Some(e)),
+    };
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    PartReport {
+        day,
+        part,
+        answer,
+        error,
+        peak_bytes: memory::peak_bytes(),
+        stats,
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// `hook` receives the result of the first execution and returns whether the part should be benched.
+/// Panics are caught and returned as an error message; a part that panics is not benched.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    is_timed: bool,
+    hook: impl Fn(&T) -> bool,
+) -> (Result<T, String>, BenchStats) {
     let timer = Instant::now();
     let result = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        catch_panic(|| func(input))
     };
     let base_time = timer.elapsed();
 
-    hook(&result);
+    let should_bench = result.as_ref().is_ok_and(hook);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if is_timed && should_bench {
+        // benching only repeats the first run, its own bookkeeping should not count towards the peak memory.
+        let _paused = memory::pause();
+        catch_panic(|| bench(&func, input, &base_time))
+            .unwrap_or_else(|_| BenchStats::from_samples(&[base_time]))
     } else {
-        (base_time, 1)
+        BenchStats::from_samples(&[base_time])
     };
 
-    (result, run.0, run.1)
+    (result, stats)
+}
+
+thread_local! {
+    /// Whether the current thread is inside [`catch_panic`]. Only its panics are kept from the default hook.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// Location of the last panic caught on the current thread, recorded by the hook installed in [`catch_panic`].
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Run a function, returning its panic message instead of unwinding if it panics.
+/// Caught panics are not printed by the default panic hook, so they are only printed once, by the caller.
+/// Panics of other threads, e.g. rayon's workers, are printed by the default hook as usual.
+fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, String> {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                PANIC_LOCATION.set(info.location().map(ToString::to_string));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+
+    let was_catching = CATCHING.replace(true);
+    PANIC_LOCATION.set(None);
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    CATCHING.set(was_catching);
+
+    result.map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| (*s).to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic payload".into());
+
+        match PANIC_LOCATION.take() {
+            Some(location) => format!("panicked at {location}: {message}"),
+            None => format!("panicked: {message}"),
+        }
+    })
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> BenchStats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
@@ -67,35 +341,92 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+/// Summary statistics over the samples collected while benching a solution part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub std_dev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub p95: Duration,
+    pub samples: u128,
+    /// Number of samples outside of the Tukey fences (1.5 × IQR below Q1 or above Q3).
+    pub outliers: usize,
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+impl BenchStats {
+    /// Computes statistics for a non-empty set of samples.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        sorted.sort_unstable();
+
+        let count = sorted.len();
+        let mean = sorted.iter().sum::<u128>() / count as u128;
+
+        let variance = sorted
+            .iter()
+            .map(|&x| (x as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        let q1 = percentile(&sorted, 25);
+        let q3 = percentile(&sorted, 75);
+        let fence = (q3 - q1) * 3 / 2;
+        let outliers = sorted
+            .iter()
+            .filter(|&&x| x + fence < q1 || x > q3 + fence)
+            .count();
+
+        let to_duration = |nanos: u128| Duration::from_nanos(nanos as u64);
+
+        Self {
+            mean: to_duration(mean),
+            median: to_duration(percentile(&sorted, 50)),
+            std_dev: Duration::from_nanos(variance.sqrt() as u64),
+            min: to_duration(sorted[0]),
+            max: to_duration(sorted[count - 1]),
+            p95: to_duration(percentile(&sorted, 95)),
+            samples: count as u128,
+            outliers,
+        }
+    }
+}
+
+/// Nearest-rank percentile of an ascending, non-empty slice.
+fn percentile(sorted: &[u128], pct: usize) -> u128 {
+    let rank = (pct * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+fn format_duration(stats: &BenchStats) -> String {
+    if stats.samples == 1 {
+        format!(" ({:.1?})", stats.mean)
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({:.1?} @ {} samples; median {:.1?}, stddev {:.1?}, min {:.1?}, max {:.1?}, p95 {:.1?}, outliers {})",
+            stats.mean,
+            stats.samples,
+            stats.median,
+            stats.std_dev,
+            stats.min,
+            stats.max,
+            stats.p95,
+            stats.outliers
+        )
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result(result: &Result<Option<String>, String>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
+        Ok(Some(result)) => {
+            if result.contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
@@ -114,7 +445,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Ok(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -122,17 +453,24 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        Err(e) => {
+            if is_intermediate_result {
+                print!("{part}: ✖ {e}");
+            } else {
+                print!("\r");
+                println!("{part}: ✖ {e}");
+            }
+        }
     }
 }
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. aoc-cli is installed, or the built-in client is enabled via the `aoc-client` feature.
+///
+/// Answers that are known to be wrong from previous submissions are not submitted again.
+/// Returns the verdict of the website.
+fn submit_result<T: Display>(result: T, day: Day, part: u8) -> Option<Verdict> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -155,6 +493,56 @@ fn submit_result<T: Display>(
         return None;
     }
 
+    let answer = result.to_string();
+    // NOTE: without the log, answers that are known to be wrong can not be told apart, and the log would be overwritten.
+    let mut submissions = match Submissions::read_from_file() {
+        Ok(submissions) => submissions,
+        Err(e) => {
+            eprintln!("Failed to read previous submissions, not submitting: {e}");
+            return None;
+        }
+    };
+
+    if let Some(reason) = submissions.check(day, part, &answer) {
+        eprintln!("Not submitting: {reason}");
+        return None;
+    }
+
+    let response = submit_answer(day, part, &answer).ok()?;
+    let verdict = Verdict::parse(&response);
+    println!("{verdict}");
+
+    if verdict.is_conclusive() {
+        submissions.record(day, part, &answer, &verdict);
+        if let Err(e) = submissions.store_file() {
+            eprintln!("Failed to store submission: {e}");
+        }
+    }
+
+    Some(verdict)
+}
+
+#[cfg(feature = "aoc-client")]
+fn submit_answer(day: Day, part: u8, answer: &str) -> Result<String, String> {
+    use crate::template::aoc_client::AocClient;
+
+    println!("Submitting result...");
+    let response = AocClient::from_env()
+        .and_then(|client| client.submit(day, part, answer))
+        .map_err(|e| e.to_string());
+
+    match &response {
+        Ok(response) => println!("{response}"),
+        Err(e) => eprintln!("Failed to submit result: {e}"),
+    }
+
+    response
+}
+
+#[cfg(not(feature = "aoc-client"))]
+fn submit_answer(day: Day, part: u8, answer: &str) -> Result<String, String> {
+    use crate::template::aoc_cli;
+
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -163,5 +551,129 @@ fn submit_result<T: Display>(
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    aoc_cli::submit(day, part, answer)
+        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BenchStats, PartResult, catch_panic, format_duration, report_part};
+    use crate::day;
+    use std::{sync::Barrier, thread, time::Duration};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn computes_stats_for_single_sample() {
+        let stats = BenchStats::from_samples(&nanos(&[42]));
+        assert_eq!(stats.mean, Duration::from_nanos(42));
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.p95, Duration::from_nanos(42));
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn computes_stats_for_samples() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 20, 30, 40, 50, 60, 70, 80, 90, 100]));
+        assert_eq!(stats.mean, Duration::from_nanos(55));
+        assert_eq!(stats.median, Duration::from_nanos(50));
+        assert_eq!(stats.std_dev, Duration::from_nanos(28));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(100));
+        assert_eq!(stats.p95, Duration::from_nanos(100));
+        assert_eq!(stats.samples, 10);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn detects_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 11, 10, 12, 500]));
+        assert_eq!(stats.median, Duration::from_nanos(11));
+        assert_eq!(stats.max, Duration::from_nanos(500));
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn formats_stats() {
+        let stats = BenchStats::from_samples(&nanos(&[1000, 2000, 3000]));
+        assert_eq!(
+            format_duration(&stats),
+            " (2.0µs @ 3 samples; median 2.0µs, stddev 816.0ns, min 1.0µs, max 3.0µs, p95 3.0µs, outliers 0)"
+        );
+        assert_eq!(
+            format_duration(&BenchStats::from_samples(&nanos(&[1500]))),
+            " (1.5µs)"
+        );
+    }
+
+    #[test]
+    fn converts_part_results() {
+        assert_eq!(Some(42).to_answer(), Ok(Some("42".into())));
+        assert_eq!(None::<u32>.to_answer(), Ok(None));
+        assert_eq!(Ok::<u32, &str>(42).to_answer(), Ok(Some("42".into())));
+        assert_eq!(
+            Err::<u32, &str>("no path").to_answer(),
+            Err("no path".into())
+        );
+    }
+
+    #[test]
+    fn catches_panics() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+
+        let error = catch_panic(|| -> u32 { panic!("bad input {}", 7) }).unwrap_err();
+        assert!(error.starts_with("panicked at src/template/runner.rs:"));
+        assert!(error.ends_with(": bad input 7"));
+    }
+
+    #[test]
+    fn attributes_panics_to_their_thread() {
+        let barrier = Barrier::new(8);
+
+        thread::scope(|scope| {
+            for i in 0..8 {
+                let barrier = &barrier;
+                scope.spawn(move || {
+                    for _ in 0..50 {
+                        barrier.wait();
+                        let (line, error) = if i % 2 == 0 {
+                            (line!(), catch_panic(|| -> u32 { panic!("even {i}") }))
+                        } else {
+                            (line!(), catch_panic(|| -> u32 { panic!("odd {i}") }))
+                        };
+                        let error = error.unwrap_err();
+                        assert!(error.contains(&format!("runner.rs:{line}:")), "{error}");
+                        assert!(error.ends_with(&format!(" {i}")), "{error}");
+                    }
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn reports_failing_parts() {
+        let report = report_part(
+            |_: &str| -> Option<u32> { panic!("oops") },
+            "",
+            day!(1),
+            1,
+            true,
+        );
+        assert_eq!(report.answer, None);
+        assert!(report.error.unwrap().ends_with(": oops"));
+        assert_eq!(report.stats.samples, 1);
+
+        let report = report_part(|_: &str| Err::<u32, _>("no path"), "", day!(1), 2, false);
+        assert_eq!(report.answer, None);
+        assert_eq!(report.error.as_deref(), Some("no path"));
+
+        let report = report_part(|input: &str| Some(input.len()), "abc", day!(1), 2, false);
+        assert_eq!(report.answer.as_deref(), Some("3"));
+        assert_eq!(report.error, None);
+    }
 }
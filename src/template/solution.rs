@@ -0,0 +1,165 @@
+/// The solution of a day as a type, implemented by the [`solution!`](crate::solution) macro.
+/// Lets tooling parse inputs, run parts and look up a day's metadata without going through its binary.
+use std::fs;
+
+use crate::template::puzzle::PuzzleExamples;
+use crate::template::report::PARSE_PART;
+use crate::template::runner::PartResult;
+use crate::template::{Context, Day, example_files};
+
+/// A solution of a day.
+/// The `solution!` macro implements it for the unit struct `DaySolution` in every solution binary.
+///
+/// ```ignore
+/// use advent_of_code::template::{Context, InputSource, Solution};
+///
+/// let input = "...";
+/// let ctx = Context::new(InputSource::Puzzle);
+/// let parsed = DaySolution::parse(input);
+/// let answer = DaySolution::part_one(&parsed, &ctx).to_answer();
+/// ```
+pub trait Solution {
+    /// The parsed input, shared by both parts. The input itself for solutions without a parse function.
+    type Parsed<'a>;
+
+    const DAY: Day;
+
+    /// The parts the solution reports on, in the order they run, including [`PARSE_PART`] if it has a parse function.
+    const PARTS: &'static [u8];
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+
+    /// Solve part one. Solutions without part one are not solved, i.e. return `None`.
+    fn part_one(_parsed: &Self::Parsed<'_>, _ctx: &Context) -> impl PartResult {
+        None::<u8>
+    }
+
+    /// Solve part two. Solutions without part two are not solved, i.e. return `None`.
+    fn part_two(_parsed: &Self::Parsed<'_>, _ctx: &Context) -> impl PartResult {
+        None::<u8>
+    }
+
+    /// Whether the input is parsed by a separate parse function, which is timed on its own.
+    fn has_parse() -> bool {
+        Self::PARTS.contains(&PARSE_PART)
+    }
+
+    /// The puzzle title, e.g. "Day 1: Trebuchet?!", if the puzzle description was downloaded. "Day 1" otherwise.
+    fn name() -> String {
+        fs::read_to_string(format!("data/puzzles/{}.md", Self::DAY))
+            .ok()
+            .and_then(|puzzle| PuzzleExamples::title(&puzzle))
+            .unwrap_or_else(|| format!("Day {}", Self::DAY.into_inner()))
+    }
+
+    /// Names of the example files of the day in `data/examples`, e.g. `01.txt` and `01-2.txt`.
+    fn examples() -> Vec<String> {
+        example_files(Self::DAY)
+    }
+
+    /// Parse an input and solve every part of the solution.
+    /// Returns the answer of every part, or its error message, see [`PartResult::to_answer`].
+    fn solve(input: &str, ctx: &Context) -> Vec<(u8, Result<Option<String>, String>)> {
+        let parsed = Self::parse(input);
+
+        Self::PARTS
+            .iter()
+            .filter_map(|part| match part {
+                1 => Some((1, Self::part_one(&parsed, ctx).to_answer())),
+                2 => Some((2, Self::part_two(&parsed, ctx).to_answer())),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Solution;
+    use crate::day;
+    use crate::template::{Context, InputSource, report::PARSE_PART, runner::PartResult};
+
+    #[allow(dead_code)]
+    mod with_parse {
+        use crate::template::Context;
+
+        crate::solution!(1, parse = parse_input -> Vec<&'a str>);
+
+        fn parse_input(input: &str) -> Vec<&str> {
+            input.split(',').collect()
+        }
+
+        fn part_one(items: &[&str]) -> Option<usize> {
+            Some(items.len())
+        }
+
+        fn part_two(items: &[&str], ctx: &Context) -> Result<String, String> {
+            if ctx.is_example() {
+                return Err("no example".into());
+            }
+            Ok(items.concat())
+        }
+    }
+
+    #[allow(dead_code)]
+    mod single_part {
+        crate::solution!(2, 2);
+
+        fn part_two(input: &str) -> Option<usize> {
+            Some(input.len())
+        }
+    }
+
+    #[test]
+    fn implements_solution() {
+        type S = with_parse::DaySolution;
+        assert_eq!(S::DAY, day!(1));
+        assert_eq!(S::PARTS, &[PARSE_PART, 1, 2]);
+        assert!(S::has_parse());
+        assert_eq!(S::parse("a,b"), vec!["a", "b"]);
+
+        let ctx = Context::new(InputSource::Puzzle);
+        let parsed = S::parse("a,b,c");
+        assert_eq!(S::part_one(&parsed, &ctx).to_answer(), Ok(Some("3".into())));
+        assert_eq!(
+            S::part_two(&parsed, &ctx).to_answer(),
+            Ok(Some("abc".into()))
+        );
+    }
+
+    #[test]
+    fn solves_all_parts() {
+        let puzzle = Context::new(InputSource::Puzzle);
+        let example = Context::new(InputSource::Example);
+
+        assert_eq!(
+            with_parse::DaySolution::solve("a,b", &puzzle),
+            vec![(1, Ok(Some("2".into()))), (2, Ok(Some("ab".into())))]
+        );
+        assert_eq!(
+            with_parse::DaySolution::solve("a,b", &example)[1],
+            (2, Err("no example".into()))
+        );
+        assert_eq!(
+            single_part::DaySolution::solve("abcd", &puzzle),
+            vec![(2, Ok(Some("4".into())))]
+        );
+    }
+
+    #[test]
+    fn defaults_missing_parts() {
+        type S = single_part::DaySolution;
+        assert!(!S::has_parse());
+        assert_eq!(
+            S::part_one(&"abc", &Context::new(InputSource::Puzzle)).to_answer(),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn names_days_without_puzzle() {
+        assert_eq!(single_part::DaySolution::name(), "Day 2");
+    }
+}
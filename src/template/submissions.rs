@@ -0,0 +1,416 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+static SUBMISSIONS_FILE_PATH: &str = "./data/submissions.json";
+
+/// Represents the response of the website to a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The answer is wrong, without a hint in which direction.
+    Wrong,
+    /// An answer was submitted too recently.
+    RateLimited(Option<Duration>),
+    /// The part has been solved already.
+    AlreadySolved,
+    /// The response could not be interpreted.
+    Unknown,
+}
+
+impl Verdict {
+    /// Interpret the text of a submission response.
+    pub fn parse(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Verdict::Correct
+        } else if response.contains("That's not the right answer") {
+            if response.contains("too high") {
+                Verdict::TooHigh
+            } else if response.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if response.contains("You gave an answer too recently") {
+            Verdict::RateLimited(parse_wait_time(response))
+        } else if response.contains("Did you already complete it") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Whether the verdict says anything about the answer itself and should be logged.
+    pub fn is_conclusive(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong
+        )
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited(_) => "rate_limited",
+            Verdict::AlreadySolved => "already_solved",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "correct" => Some(Verdict::Correct),
+            "too_high" => Some(Verdict::TooHigh),
+            "too_low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            "rate_limited" => Some(Verdict::RateLimited(None)),
+            "already_solved" => Some(Verdict::AlreadySolved),
+            "unknown" => Some(Verdict::Unknown),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "✔ That's the right answer."),
+            Verdict::TooHigh => write!(f, "✘ Wrong answer, it is too high."),
+            Verdict::TooLow => write!(f, "✘ Wrong answer, it is too low."),
+            Verdict::Wrong => write!(f, "✘ Wrong answer."),
+            Verdict::RateLimited(Some(wait)) => {
+                write!(
+                    f,
+                    "⏳ Rate-limited, wait {}s before submitting.",
+                    wait.as_secs()
+                )
+            }
+            Verdict::RateLimited(None) => write!(f, "⏳ Rate-limited, wait before submitting."),
+            Verdict::AlreadySolved => write!(f, "This part has been solved already."),
+            Verdict::Unknown => write!(f, "Could not interpret the response."),
+        }
+    }
+}
+
+/// Parse the wait time out of e.g. "You have 1m 5s left to wait."
+fn parse_wait_time(response: &str) -> Option<Duration> {
+    let (before, _) = response.split_once(" left to wait")?;
+    let time = before.rsplit_once("You have ")?.1;
+
+    let mut secs = 0;
+    for token in time.split_whitespace() {
+        if let Some(m) = token.strip_suffix('m') {
+            secs += m.parse::<u64>().ok()? * 60;
+        } else if let Some(s) = token.strip_suffix('s') {
+            secs += s.parse::<u64>().ok()?;
+        } else {
+            return None;
+        }
+    }
+
+    Some(Duration::from_secs(secs))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Represents a single submitted answer and its verdict.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Represents all logged submissions.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(SUBMISSIONS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns empty submissions.
+    /// Fails if the file can not be read or is malformed, so that it is not overwritten by mistake.
+    pub fn read_from_file() -> Result<Self, String> {
+        Self::read_from(Path::new(SUBMISSIONS_FILE_PATH))
+    }
+
+    fn read_from(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                Submissions::try_from(contents).map_err(|e| format!("{}: {e}", path.display()))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    /// Log a submission if its verdict says anything about the answer.
+    pub fn record(&mut self, day: Day, part: u8, answer: &str, verdict: &Verdict) {
+        if verdict.is_conclusive() {
+            self.data.push(Submission {
+                day,
+                part,
+                answer: answer.into(),
+                verdict: verdict.clone(),
+            });
+        }
+    }
+
+    /// Check an answer against previous submissions of the same part.
+    /// Returns the reason why it should not be submitted, if any.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Option<String> {
+        let previous = self.data.iter().filter(|s| s.day == day && s.part == part);
+        let value = answer.trim().parse::<i128>().ok();
+
+        for submission in previous {
+            if submission.verdict == Verdict::Correct {
+                return Some(format!(
+                    "part {part} was already solved with the answer {}.",
+                    submission.answer
+                ));
+            }
+
+            if submission.answer == answer {
+                return Some(format!("{answer} was already submitted and is wrong."));
+            }
+
+            let bound = submission.answer.trim().parse::<i128>().ok();
+
+            match (&submission.verdict, value, bound) {
+                (Verdict::TooHigh, Some(value), Some(bound)) if value >= bound => {
+                    return Some(format!("{answer} is too high, {bound} already was."));
+                }
+                (Verdict::TooLow, Some(value), Some(bound)) if value <= bound => {
+                    return Some(format!("{answer} is too low, {bound} already was."));
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.as_str().into()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .and_then(|v| Verdict::from_str(v))
+            .ok_or("Expected submission.verdict to be a verdict.")?;
+
+        Ok(Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            verdict,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Submissions, Verdict};
+    use crate::day;
+    use std::{env, fs, process, time::Duration};
+
+    fn get_mock_submissions() -> Submissions {
+        let mut submissions = Submissions::default();
+        submissions.record(day!(1), 1, "500", &Verdict::TooHigh);
+        submissions.record(day!(1), 1, "100", &Verdict::TooLow);
+        submissions.record(day!(1), 1, "abc", &Verdict::Wrong);
+        submissions.record(day!(1), 2, "42", &Verdict::Correct);
+        submissions.record(day!(2), 1, "7", &Verdict::RateLimited(None));
+        submissions
+    }
+
+    #[test]
+    fn reads_submission_files() {
+        let missing =
+            env::temp_dir().join(format!("aoc-submissions-missing-{}.json", process::id()));
+        assert!(Submissions::read_from(&missing).unwrap().data.is_empty());
+
+        let corrupt =
+            env::temp_dir().join(format!("aoc-submissions-corrupt-{}.json", process::id()));
+        fs::write(&corrupt, "[").unwrap();
+        let result = Submissions::read_from(&corrupt);
+        fs::remove_file(&corrupt).unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::parse("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too high."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer. If you're stuck, please wait one minute."),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(Verdict::parse("<html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn parses_wait_times() {
+        assert_eq!(
+            Verdict::parse("You gave an answer too recently. You have 1m 5s left to wait."),
+            Verdict::RateLimited(Some(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            Verdict::parse("You gave an answer too recently. You have 34s left to wait."),
+            Verdict::RateLimited(Some(Duration::from_secs(34)))
+        );
+    }
+
+    #[test]
+    fn records_conclusive_verdicts() {
+        assert_eq!(get_mock_submissions().data.len(), 4);
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let submissions = get_mock_submissions();
+        assert!(submissions.check(day!(1), 1, "abc").is_some());
+        assert!(submissions.check(day!(1), 1, "500").is_some());
+        assert!(submissions.check(day!(1), 2, "43").is_some());
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let submissions = get_mock_submissions();
+        assert!(submissions.check(day!(1), 1, "501").is_some());
+        assert!(submissions.check(day!(1), 1, "99").is_some());
+        assert!(submissions.check(day!(1), 1, "250").is_none());
+        assert!(submissions.check(day!(2), 1, "7").is_none());
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let submissions = get_mock_submissions();
+        let json = tinyjson::JsonValue::from(submissions.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Submissions::try_from(json).unwrap();
+        assert_eq!(parsed.data, submissions.data);
+    }
+}
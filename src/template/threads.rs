@@ -0,0 +1,87 @@
+/// Control over the size of rayon's thread pool.
+/// Solutions that use rayon run on its global pool, which defaults to one thread per core. Fixing the number of
+/// threads with `--threads <n>` makes benchmarks comparable between machines.
+use std::env;
+
+/// Configure the global thread pool of a solution binary, if `--threads <n>` was passed to it.
+/// Must be called before rayon is first used.
+pub fn configure_global() {
+    let args: Vec<String> = env::args().collect();
+
+    let Some(threads) = args
+        .iter()
+        .position(|x| x == "--threads")
+        .and_then(|index| args.get(index + 1))
+    else {
+        return;
+    };
+
+    let result = threads
+        .parse()
+        .map_err(|_| format!("`--threads` expects a number, got `{threads}`."))
+        .and_then(|threads| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build_global()
+                .map_err(|e| e.to_string())
+        });
+
+    if let Err(e) = result {
+        eprintln!("Failed to configure the thread pool: {e}");
+    }
+}
+
+/// Run `op` on a thread pool with `threads` threads, or on the global pool if `None`.
+/// Used to run solutions in-process, where the global pool is shared by all days.
+pub fn install<R: Send>(threads: Option<usize>, op: impl FnOnce() -> R + Send) -> R {
+    let pool = threads.and_then(|threads| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .inspect_err(|e| eprintln!("Failed to create a thread pool: {e}"))
+            .ok()
+    });
+
+    match pool {
+        Some(pool) => pool.install(op),
+        None => op(),
+    }
+}
+
+/// The number of threads solutions run with, `threads` or the size of the global pool otherwise.
+pub fn effective(threads: Option<usize>) -> usize {
+    threads.unwrap_or_else(rayon::current_num_threads)
+}
+
+/// Thread counts that are benched by `cargo time --scaling`: powers of two up to `max`, and `max` itself.
+pub fn scaling_steps(max: usize) -> Vec<usize> {
+    let mut steps: Vec<usize> = (0..usize::BITS)
+        .map(|exp| 1 << exp)
+        .take_while(|threads| *threads < max)
+        .collect();
+    steps.push(max.max(1));
+    steps
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{effective, install, scaling_steps};
+
+    #[test]
+    fn computes_scaling_steps() {
+        assert_eq!(scaling_steps(0), vec![1]);
+        assert_eq!(scaling_steps(1), vec![1]);
+        assert_eq!(scaling_steps(4), vec![1, 2, 4]);
+        assert_eq!(scaling_steps(6), vec![1, 2, 4, 6]);
+        assert_eq!(scaling_steps(16), vec![1, 2, 4, 8, 16]);
+    }
+
+    #[test]
+    fn installs_thread_pool() {
+        assert_eq!(install(Some(3), rayon::current_num_threads), 3);
+        assert_eq!(install(Some(3), || effective(None)), 3);
+        assert_eq!(effective(Some(5)), 5);
+    }
+}
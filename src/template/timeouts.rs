@@ -0,0 +1,124 @@
+/// Time limits for `cargo all` and `cargo time`, see [`Timeouts`].
+use std::time::Duration;
+
+use crate::template::Day;
+
+/// The time every part and parse function of a day may take before its solution is killed.
+/// A default limit, overridden per day or per part of a day, e.g. `--timeout 10 --timeout 08=30 --timeout 10:2=60`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Timeouts {
+    default: Option<Duration>,
+    /// Limits of a day, or of a single part of it. Later limits replace earlier ones of the same day or part.
+    overrides: Vec<(Day, Option<u8>, Duration)>,
+}
+
+impl Timeouts {
+    /// Parse the values of all `--timeout` options: `<seconds>` sets the default limit, `<day>=<seconds>` the limit of
+    /// a day and `<day>:<part>=<seconds>` the limit of a single part.
+    pub fn parse(specs: &[String]) -> Result<Self, String> {
+        let mut timeouts = Self::default();
+
+        for spec in specs {
+            let Some((target, seconds)) = spec.split_once('=') else {
+                timeouts.default = Some(parse_seconds(spec)?);
+                continue;
+            };
+
+            let (day, part) = match target.split_once(':') {
+                Some((day, part)) => (day, Some(part)),
+                None => (target, None),
+            };
+
+            let day: Day = day
+                .parse()
+                .map_err(|e| format!("invalid day in `--timeout {spec}`: {e}."))?;
+
+            let part = part
+                .map(|part| match part {
+                    "1" => Ok(1),
+                    "2" => Ok(2),
+                    _ => Err(format!(
+                        "invalid part in `--timeout {spec}`, expected 1 or 2."
+                    )),
+                })
+                .transpose()?;
+
+            timeouts
+                .overrides
+                .push((day, part, parse_seconds(seconds)?));
+        }
+
+        Ok(timeouts)
+    }
+
+    /// The limit of a part of a day, if any. The limit of the part takes precedence over the limit of its day, which
+    /// takes precedence over the default. Parse functions are limited by the limit of their day.
+    pub fn limit(&self, day: Day, part: u8) -> Option<Duration> {
+        let find = |target: Option<u8>| {
+            self.overrides
+                .iter()
+                .rev()
+                .find(|(d, p, _)| *d == day && *p == target)
+                .map(|(_, _, limit)| *limit)
+        };
+
+        find(Some(part)).or_else(|| find(None)).or(self.default)
+    }
+
+    /// Whether any part of a day is limited.
+    pub fn applies_to(&self, day: Day) -> bool {
+        self.default.is_some() || self.overrides.iter().any(|(d, _, _)| *d == day)
+    }
+}
+
+fn parse_seconds(seconds: &str) -> Result<Duration, String> {
+    seconds
+        .parse::<f64>()
+        .map_err(|_| format!("`--timeout` expects a number of seconds, got `{seconds}`."))
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).map_err(|e| e.to_string()))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Timeouts;
+    use crate::{day, template::report::PARSE_PART};
+    use std::time::Duration;
+
+    fn parse(specs: &[&str]) -> Result<Timeouts, String> {
+        Timeouts::parse(&specs.iter().map(ToString::to_string).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn resolves_limits() {
+        let timeouts = parse(&["10", "08:2=60.5", "08=30", "03:1=1"]).unwrap();
+        let secs = Duration::from_secs_f64;
+
+        assert_eq!(timeouts.limit(day!(1), 1), Some(secs(10.0)));
+        assert_eq!(timeouts.limit(day!(8), PARSE_PART), Some(secs(30.0)));
+        assert_eq!(timeouts.limit(day!(8), 1), Some(secs(30.0)));
+        assert_eq!(timeouts.limit(day!(8), 2), Some(secs(60.5)));
+        assert_eq!(timeouts.limit(day!(3), 1), Some(secs(1.0)));
+        assert_eq!(timeouts.limit(day!(3), 2), Some(secs(10.0)));
+        assert!(timeouts.applies_to(day!(5)));
+    }
+
+    #[test]
+    fn limits_single_parts() {
+        let timeouts = parse(&["08:2=60"]).unwrap();
+        assert_eq!(timeouts.limit(day!(8), 1), None);
+        assert_eq!(timeouts.limit(day!(8), 2), Some(Duration::from_secs(60)));
+        assert!(timeouts.applies_to(day!(8)));
+        assert!(!timeouts.applies_to(day!(5)));
+        assert_eq!(parse(&[]).unwrap(), Timeouts::default());
+    }
+
+    #[test]
+    fn rejects_invalid_limits() {
+        assert!(parse(&["ten"]).is_err());
+        assert!(parse(&["08:3=10"]).is_err());
+        assert!(parse(&["99=10"]).is_err());
+        assert!(parse(&["-1"]).is_err());
+    }
+}
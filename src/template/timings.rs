@@ -1,7 +1,7 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{Day, report::PARSE_PART, runner::BenchStats};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
@@ -11,9 +11,104 @@ pub struct Timing {
     pub day: Day,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_stats: Option<PartStats>,
+    pub part_2_stats: Option<PartStats>,
+    /// Duration of the shared parse phase, if the solution has one.
+    pub parse: Option<String>,
+    pub parse_stats: Option<PartStats>,
+    /// The part, or [`PARSE_PART`], that was killed after exceeding the time limit.
+    pub timed_out: Option<u8>,
+    /// Peak heap usage of the solution, if it was measured with `--memory`.
+    pub peak_bytes: Option<u64>,
+    /// Number of threads in the thread pool the solution ran on.
+    pub threads: Option<usize>,
     pub total_nanos: f64,
 }
 
+/// Represents the sample distribution of a benched part, in nanoseconds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartStats {
+    /// Exact mean of the samples. The display string of a part is rounded. `None` for timings stored without it.
+    pub mean_nanos: Option<f64>,
+    pub median_nanos: f64,
+    pub std_dev_nanos: f64,
+    pub min_nanos: f64,
+    pub max_nanos: f64,
+    pub p95_nanos: f64,
+    pub samples: u64,
+    pub outliers: u64,
+}
+
+/// Represents the change in execution time of a single part relative to stored timings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimingDelta {
+    pub day: Day,
+    pub part: u8,
+    pub before_nanos: f64,
+    pub after_nanos: f64,
+}
+
+impl TimingDelta {
+    /// Relative change in percent. Positive values are slowdowns.
+    pub fn change_pct(&self) -> f64 {
+        (self.after_nanos - self.before_nanos) / self.before_nanos * 100_f64
+    }
+
+    /// Whether the part slowed down by more than `threshold_pct` percent.
+    pub fn is_regression(&self, threshold_pct: f64) -> bool {
+        self.change_pct() > threshold_pct
+    }
+}
+
+impl Timing {
+    /// Timings of a day without any benched part.
+    #[must_use]
+    pub fn new(day: Day) -> Self {
+        Self {
+            day,
+            part_1: None,
+            part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse: None,
+            parse_stats: None,
+            timed_out: None,
+            peak_bytes: None,
+            threads: None,
+            total_nanos: 0_f64,
+        }
+    }
+
+    /// Whether both timings know their thread pool size and it differs, which makes their times incomparable.
+    pub fn ran_on_other_threads(&self, other: &Self) -> bool {
+        matches!((self.threads, other.threads), (Some(a), Some(b)) if a != b)
+    }
+
+    /// Get the display string and stats of a part, or of the parse phase for [`PARSE_PART`].
+    pub fn part(&self, part: u8) -> (Option<&str>, Option<&PartStats>) {
+        match part {
+            PARSE_PART => (self.parse.as_deref(), self.parse_stats.as_ref()),
+            1 => (self.part_1.as_deref(), self.part_1_stats.as_ref()),
+            2 => (self.part_2.as_deref(), self.part_2_stats.as_ref()),
+            _ => (None, None),
+        }
+    }
+
+    /// Mark a part as killed after exceeding `limit`. Its display string becomes the limit, e.g. `>10.0s`.
+    pub fn mark_timed_out(&mut self, part: u8, limit: Duration) {
+        let limit_str = Some(format!(">{limit:.1?}"));
+
+        match part {
+            PARSE_PART => self.parse = limit_str,
+            1 => self.part_1 = limit_str,
+            2 => self.part_2 = limit_str,
+            _ => return,
+        }
+
+        self.timed_out = Some(part);
+    }
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -51,7 +146,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|t| t.day);
         Timings { data }
     }
 
@@ -61,12 +156,76 @@ impl Timings {
     }
 
     pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+        self.data.iter().any(|t| {
+            t.day == day && t.part_1.is_some() && t.part_2.is_some() && t.timed_out.is_none()
+        })
+    }
+
+    /// Compare every part of `self` with the same part in `baseline`.
+    /// Medians are compared if both sides have stats, as they are less sensitive to outliers. Means otherwise.
+    /// Days that ran on a different number of threads than in `baseline` are skipped.
+    pub fn compare(&self, baseline: &Self) -> Vec<TimingDelta> {
+        let mut deltas = vec![];
+
+        for timing in &self.data {
+            let Some(before) = baseline.data.iter().find(|t| t.day == timing.day) else {
+                continue;
+            };
+
+            if timing.ran_on_other_threads(before) {
+                continue;
+            }
+
+            for part in [PARSE_PART, 1, 2] {
+                let (after_str, after_stats) = timing.part(part);
+                let (before_str, before_stats) = before.part(part);
+
+                let nanos = match (after_stats, before_stats) {
+                    (Some(after), Some(before)) => Some((before.median_nanos, after.median_nanos)),
+                    _ => before_str
+                        .and_then(parse_duration_nanos)
+                        .zip(after_str.and_then(parse_duration_nanos)),
+                };
+
+                if let Some((before_nanos, after_nanos)) = nanos
+                    && before_nanos > 0_f64
+                {
+                    deltas.push(TimingDelta {
+                        day: timing.day,
+                        part,
+                        before_nanos,
+                        after_nanos,
+                    });
+                }
+            }
+        }
+
+        deltas
     }
 }
 
+/// Parse a duration as formatted by `{:.1?}` into nanoseconds.
+pub fn parse_duration_nanos(s: &str) -> Option<f64> {
+    let parse = |postfix: &str| s.strip_suffix(postfix)?.parse::<f64>().ok();
+
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    if s.ends_with("ns") {
+        parse("ns")
+    } else if s.ends_with("µs") {
+        parse("µs").map(|x| x * 1000_f64)
+    } else if s.ends_with("ms") {
+        parse("ms").map(|x| x * 1_000_000_f64)
+    } else {
+        parse("s").map(|x| x * 1_000_000_000_f64)
+    }
+}
+
+/// Format nanoseconds like `{:.1?}` formats a duration, the inverse of [`parse_duration_nanos`].
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<Timings> for JsonValue {
@@ -133,6 +292,61 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "part_1_stats".into(),
+            value
+                .part_1_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_stats".into(),
+            value
+                .part_2_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "parse".into(),
+            value
+                .parse
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "parse_stats".into(),
+            value
+                .parse_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "timed_out".into(),
+            value
+                .timed_out
+                .map_or(JsonValue::Null, |part| JsonValue::Number(f64::from(part))),
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "peak_bytes".into(),
+            value
+                .peak_bytes
+                .map_or(JsonValue::Null, |bytes| JsonValue::Number(bytes as f64)),
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "threads".into(),
+            value
+                .threads
+                .map_or(JsonValue::Null, |threads| JsonValue::Number(threads as f64)),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -166,10 +380,54 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: stats were added later, timings stored before that do not have them.
+        let part_1_stats = match json.get("part_1_stats") {
+            Some(v) if !v.is_null() => Some(PartStats::try_from(v)?),
+            _ => None,
+        };
+
+        let part_2_stats = match json.get("part_2_stats") {
+            Some(v) if !v.is_null() => Some(PartStats::try_from(v)?),
+            _ => None,
+        };
+
+        // NOTE: the parse phase was added later, and is optional for solutions.
+        let parse = json.get("parse").and_then(|v| v.get::<String>()).cloned();
+
+        let parse_stats = match json.get("parse_stats") {
+            Some(v) if !v.is_null() => Some(PartStats::try_from(v)?),
+            _ => None,
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timed_out = json
+            .get("timed_out")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8);
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let peak_bytes = json
+            .get("peak_bytes")
+            .and_then(|v| v.get::<f64>())
+            .map(|bytes| *bytes as u64);
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let threads = json
+            .get("threads")
+            .and_then(|v| v.get::<f64>())
+            .map(|threads| *threads as usize);
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_stats,
+            part_2_stats,
+            parse,
+            parse_stats,
+            timed_out,
+            peak_bytes,
+            threads,
             total_nanos,
         })
     }
@@ -177,6 +435,75 @@ impl TryFrom<&JsonValue> for Timing {
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&BenchStats> for PartStats {
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    fn from(value: &BenchStats) -> Self {
+        PartStats {
+            mean_nanos: Some(value.mean.as_nanos() as f64),
+            median_nanos: value.median.as_nanos() as f64,
+            std_dev_nanos: value.std_dev.as_nanos() as f64,
+            min_nanos: value.min.as_nanos() as f64,
+            max_nanos: value.max.as_nanos() as f64,
+            p95_nanos: value.p95.as_nanos() as f64,
+            samples: value.samples as u64,
+            outliers: value.outliers as u64,
+        }
+    }
+}
+
+impl From<&PartStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &PartStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        if let Some(mean_nanos) = value.mean_nanos {
+            map.insert("mean_nanos".into(), JsonValue::Number(mean_nanos));
+        }
+        map.insert("median_nanos".into(), JsonValue::Number(value.median_nanos));
+        map.insert(
+            "std_dev_nanos".into(),
+            JsonValue::Number(value.std_dev_nanos),
+        );
+        map.insert("min_nanos".into(), JsonValue::Number(value.min_nanos));
+        map.insert("max_nanos".into(), JsonValue::Number(value.max_nanos));
+        map.insert("p95_nanos".into(), JsonValue::Number(value.p95_nanos));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected timing stats.{key} to be a number."))
+        };
+
+        Ok(PartStats {
+            mean_nanos: number("mean_nanos").ok(),
+            median_nanos: number("median_nanos")?,
+            std_dev_nanos: number("std_dev_nanos")?,
+            min_nanos: number("min_nanos")?,
+            max_nanos: number("max_nanos")?,
+            p95_nanos: number("p95_nanos")?,
+            samples: number("samples")? as u64,
+            outliers: number("outliers")? as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
@@ -187,22 +514,21 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
-                    part_2: None,
                     total_nanos: 4e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -223,6 +549,20 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timing_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000, "part_1_stats": { "median_nanos": 900000, "std_dev_nanos": 5000, "min_nanos": 800000, "max_nanos": 2000000, "p95_nanos": 1500000, "samples": 100, "outliers": 3 }, "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.median_nanos, 900_000_f64);
+            assert_eq!(stats.mean_nanos, None);
+            assert_eq!(stats.p95_nanos, 1_500_000_f64);
+            assert_eq!(stats.samples, 100);
+            assert_eq!(stats.outliers, 3);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -247,7 +587,8 @@ mod tests {
 
     mod serialization {
         use super::get_mock_timings;
-        use std::collections::HashMap;
+        use crate::template::timings::{PartStats, Timings};
+        use std::{collections::HashMap, time::Duration};
         use tinyjson::JsonValue;
 
         #[test]
@@ -266,6 +607,56 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn roundtrips_timing_stats() {
+            let mut timings = get_mock_timings();
+            timings.data[0].part_1_stats = Some(PartStats {
+                mean_nanos: Some(10.5),
+                median_nanos: 10.0,
+                std_dev_nanos: 1.0,
+                min_nanos: 9.0,
+                max_nanos: 20.0,
+                p95_nanos: 15.0,
+                samples: 10_000,
+                outliers: 2,
+            });
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].part_1_stats, timings.data[0].part_1_stats);
+            assert_eq!(parsed.data[0].part_2_stats, None);
+        }
+
+        #[test]
+        fn roundtrips_timeouts() {
+            let mut timings = get_mock_timings();
+            timings.data[0].mark_timed_out(2, Duration::from_secs(10));
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].timed_out, Some(2));
+            assert_eq!(parsed.data[0].part_2, Some(">10.0s".into()));
+            assert_eq!(parsed.data[1].timed_out, None);
+        }
+
+        #[test]
+        fn roundtrips_peak_memory() {
+            let mut timings = get_mock_timings();
+            timings.data[0].peak_bytes = Some(12_345_678);
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].peak_bytes, Some(12_345_678));
+            assert_eq!(parsed.data[1].peak_bytes, None);
+        }
+
+        #[test]
+        fn roundtrips_threads() {
+            let mut timings = get_mock_timings();
+            timings.data[0].threads = Some(8);
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].threads, Some(8));
+            assert_eq!(parsed.data[1].threads, None);
+        }
     }
 
     mod is_day_complete {
@@ -273,47 +664,141 @@ mod tests {
             day,
             template::timings::{Timing, Timings},
         };
+        use std::time::Duration;
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
-                    part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+
+        #[test]
+        fn handles_timed_out_days() {
+            let mut timing = Timing {
+                part_1: Some("1ms".into()),
+                total_nanos: 1_000_000_000_f64,
+                ..Timing::new(day!(1))
+            };
+            timing.mark_timed_out(2, Duration::from_secs(5));
+            let timings = Timings { data: vec![timing] };
+
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
-                }],
+                data: vec![Timing::new(day!(1))],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+    }
+
+    mod compare {
+        use crate::{
+            day,
+            template::timings::{PartStats, Timings, parse_duration_nanos},
+        };
+
+        use super::get_mock_timings;
+
+        fn stats(median_nanos: f64) -> Option<PartStats> {
+            Some(PartStats {
+                mean_nanos: Some(median_nanos),
+                median_nanos,
+                std_dev_nanos: 0_f64,
+                min_nanos: median_nanos,
+                max_nanos: median_nanos,
+                p95_nanos: median_nanos,
+                samples: 10,
+                outliers: 0,
+            })
+        }
+
+        #[test]
+        fn parses_durations() {
+            assert_eq!(parse_duration_nanos("74.1ns"), Some(74.1));
+            assert_eq!(parse_duration_nanos("1.5µs"), Some(1500_f64));
+            assert_eq!(parse_duration_nanos("20ms"), Some(20_000_000_f64));
+            assert_eq!(parse_duration_nanos("2.0s"), Some(2_000_000_000_f64));
+            assert_eq!(parse_duration_nanos("-"), None);
+        }
+
+        #[test]
+        fn compares_means_without_stats() {
+            let baseline = get_mock_timings();
+            let mut timings = get_mock_timings();
+            timings.data[0].part_1 = Some("12ms".into());
+
+            let deltas = timings.compare(&baseline);
+            assert_eq!(deltas.len(), 5);
+            assert_eq!(deltas[0].day, day!(1));
+            assert_eq!(deltas[0].part, 1);
+            assert_eq!(deltas[0].change_pct(), 20_f64);
+            assert_eq!(deltas[0].is_regression(10_f64), true);
+            assert_eq!(deltas[1].change_pct(), 0_f64);
+            assert_eq!(deltas[1].is_regression(10_f64), false);
+        }
+
+        #[test]
+        fn compares_medians_with_stats() {
+            let mut baseline = get_mock_timings();
+            baseline.data[1].part_2_stats = stats(100_f64);
+            let mut timings = get_mock_timings();
+            timings.data[1].part_2 = Some("80ms".into());
+            timings.data[1].part_2_stats = stats(90_f64);
+
+            let delta = timings
+                .compare(&baseline)
+                .into_iter()
+                .find(|d| d.day == day!(2) && d.part == 2)
+                .unwrap();
+            assert_eq!(delta.change_pct(), -10_f64);
+        }
+
+        #[test]
+        fn skips_unknown_days() {
+            let timings = get_mock_timings();
+            assert_eq!(timings.compare(&Timings::default()).len(), 0);
+        }
+
+        #[test]
+        fn skips_days_on_other_threads() {
+            let mut baseline = get_mock_timings();
+            baseline.data[0].threads = Some(1);
+            let mut timings = get_mock_timings();
+            timings.data[0].part_1 = Some("12ms".into());
+            timings.data[0].threads = Some(8);
+
+            let deltas = timings.compare(&baseline);
+            assert_eq!(deltas.len(), 3);
+            assert!(deltas.iter().all(|d| d.day != day!(1)));
+
+            // timings stored without a thread count are still compared.
+            baseline.data[0].threads = None;
+            assert_eq!(timings.compare(&baseline).len(), 5);
         }
     }
 
@@ -329,12 +814,7 @@ mod tests {
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(3))],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -349,12 +829,7 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(2))],
             };
             let merged = timings.merge(&other);
 